@@ -0,0 +1,159 @@
+//! Strict configuration validation with actionable diagnostics.
+//!
+//! Catches mistakes that `toml::from_str` silently accepts (a missing source
+//! directory, a malformed glob, a bad base URL) and reports every problem in
+//! one run with the offending value and, where available, its line in the
+//! config file.
+
+use crate::config::Config;
+use colored::Colorize;
+use std::fmt;
+use std::path::Path;
+
+/// A single configuration problem, optionally anchored to a config line.
+#[derive(Debug, Clone)]
+pub struct ConfigError {
+    /// Human-readable, actionable description.
+    pub message: String,
+    /// 1-based line in the config file, when it can be located.
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}: {}", format!("line {line}").dimmed(), self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Validate a loaded config against the raw TOML source it came from.
+///
+/// `raw` is the original file content, used to locate the line of an offending
+/// glob or URL so the report can point at it. Returns every error found rather
+/// than failing on the first.
+pub fn validate(config: &Config, raw: &str) -> Vec<ConfigError> {
+    let mut errors = Vec::new();
+
+    validate_source_dir(config, &mut errors);
+    validate_globs(&config.python.include, raw, &mut errors);
+    validate_globs(&config.python.exclude, raw, &mut errors);
+    validate_base_url(&config.api.base_url, raw, &mut errors);
+    validate_output_parent(config, &mut errors);
+
+    errors
+}
+
+fn validate_source_dir(config: &Config, errors: &mut Vec<ConfigError>) {
+    for source in &config.python.source_dirs {
+        let dir = source.path();
+        if !dir.exists() {
+            // Optional sources may legitimately be absent; they are skipped
+            // with a warning at parse time rather than failing validation.
+            if source.required() {
+                errors.push(ConfigError {
+                    message: format!("python.source_dir does not exist: {}", dir.display()),
+                    line: None,
+                });
+            }
+        } else if !dir.is_dir() {
+            errors.push(ConfigError {
+                message: format!("python.source_dir is not a directory: {}", dir.display()),
+                line: None,
+            });
+        }
+    }
+}
+
+fn validate_globs(patterns: &[String], raw: &str, errors: &mut Vec<ConfigError>) {
+    for pattern in patterns {
+        if let Err(e) = glob::Pattern::new(pattern) {
+            errors.push(ConfigError {
+                message: format!(
+                    "invalid glob pattern '{}' at column {}: {}",
+                    pattern, e.pos, e.msg
+                ),
+                line: locate(raw, pattern),
+            });
+        }
+    }
+}
+
+fn validate_base_url(base_url: &str, raw: &str, errors: &mut Vec<ConfigError>) {
+    let looks_like_url = base_url.contains("://")
+        && base_url
+            .split_once("://")
+            .is_some_and(|(scheme, rest)| !scheme.is_empty() && !rest.is_empty());
+    if !base_url.starts_with('/') && !looks_like_url {
+        errors.push(ConfigError {
+            message: format!(
+                "api.base_url must be an absolute URL or a leading-slash path, got '{}'",
+                base_url
+            ),
+            line: locate(raw, base_url),
+        });
+    }
+}
+
+fn validate_output_parent(config: &Config, errors: &mut Vec<ConfigError>) {
+    let output = &config.typescript.output_file;
+    if let Some(parent) = output.parent() {
+        // An empty parent means the CWD, which is always present.
+        if parent.as_os_str().is_empty() {
+            return;
+        }
+        if parent.exists() && !parent.is_dir() {
+            errors.push(ConfigError {
+                message: format!(
+                    "typescript.output_file parent is not a directory: {}",
+                    parent.display()
+                ),
+                line: None,
+            });
+        } else if parent.exists() {
+            let readonly = parent
+                .metadata()
+                .map(|m| m.permissions().readonly())
+                .unwrap_or(false);
+            if readonly {
+                errors.push(ConfigError {
+                    message: format!(
+                        "typescript.output_file parent is not writable: {}",
+                        parent.display()
+                    ),
+                    line: None,
+                });
+            }
+        }
+    }
+}
+
+/// Find the 1-based line in `raw` that contains `needle`, if any.
+fn locate(raw: &str, needle: &str) -> Option<usize> {
+    raw.lines()
+        .position(|line| line.contains(needle))
+        .map(|i| i + 1)
+}
+
+/// Validate and print a report, returning whether the config is usable.
+///
+/// `path` names the file for the report header; `raw` is its content.
+pub fn report(config: &Config, raw: &str, path: &Path) -> bool {
+    let errors = validate(config, raw);
+    if errors.is_empty() {
+        println!("{} {} is valid", "✓".green(), path.display());
+        return true;
+    }
+
+    eprintln!(
+        "{} {} has {} problem(s):",
+        "✗".red(),
+        path.display(),
+        errors.len()
+    );
+    for error in &errors {
+        eprintln!("  {} {}", "•".red(), error);
+    }
+    false
+}
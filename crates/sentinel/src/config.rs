@@ -2,6 +2,8 @@
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -11,12 +13,98 @@ pub struct Config {
     pub typescript: TypeScriptConfig,
     #[serde(default)]
     pub api: ApiConfig,
+    /// Watch-mode tuning (debounce window, error policy).
+    #[serde(default)]
+    pub watch: WatchConfig,
+    /// Additional generation targets for monorepos with several consumers.
+    ///
+    /// When empty, the top-level `[typescript]`/`[api]` form is used as a
+    /// single implicit target (backward compatible). Each entry may scope its
+    /// own `include`/`exclude` globs and carry its own `[targets.api]` block.
+    #[serde(default)]
+    pub targets: Vec<TargetConfig>,
+}
+
+/// Policy for what happens to the last good output when a reparse fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnError {
+    /// Keep the previously generated output intact.
+    Keep,
+    /// Clear the generated symbols for the failing file.
+    Clear,
+}
+
+impl Default for OnError {
+    fn default() -> Self {
+        OnError::Keep
+    }
+}
+
+/// Watch-mode configuration (`[watch]` table).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WatchConfig {
+    /// Whether incremental watch mode is enabled.
+    #[serde(default = "default_true")]
+    pub enable: bool,
+    /// Debounce window coalescing bursts of filesystem events.
+    #[serde(default = "default_debounce_ms")]
+    pub debounce_ms: u64,
+    /// What to do with the last good output when a file fails to parse.
+    #[serde(default)]
+    pub on_error: OnError,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        WatchConfig {
+            enable: true,
+            debounce_ms: default_debounce_ms(),
+            on_error: OnError::default(),
+        }
+    }
+}
+
+fn default_debounce_ms() -> u64 {
+    200
+}
+
+/// One generation target: an output file plus optional per-target overrides.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TargetConfig {
+    /// Output file for this target's generated TypeScript.
+    pub output_file: PathBuf,
+    /// Optional include globs overriding `python.include` for this target.
+    #[serde(default)]
+    pub include: Option<Vec<String>>,
+    /// Optional exclude globs overriding `python.exclude` for this target.
+    #[serde(default)]
+    pub exclude: Option<Vec<String>>,
+    /// Whether to emit runtime client code for this target.
+    #[serde(default = "default_true")]
+    pub generate_client: bool,
+    /// Per-target API settings (base_url/prefix).
+    #[serde(default)]
+    pub api: ApiConfig,
+}
+
+/// A fully-resolved target the codegen driver iterates over.
+#[derive(Debug, Clone)]
+pub struct ResolvedTarget {
+    pub output_file: PathBuf,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub generate_client: bool,
+    pub base_url: String,
+    pub prefix: String,
+    pub transport: TransportConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PythonConfig {
-    /// Directory containing Python source files
-    pub source_dir: PathBuf,
+    /// Source roots to scan, each optionally marked required. Fed by both the
+    /// `source_dirs` list and the deprecated single-value `source_dir` alias.
+    pub source_dirs: Vec<SourceDir>,
     /// File patterns to include (glob)
     #[serde(default = "default_include")]
     pub include: Vec<String>,
@@ -25,6 +113,64 @@ pub struct PythonConfig {
     pub exclude: Vec<String>,
 }
 
+/// One source root to scan for Python files.
+///
+/// Accepts either a bare path (`"backend"`, always required) or a table
+/// (`{ path = "pkg", required = false }`) so optional packages can be declared
+/// without failing the whole run when absent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SourceDir {
+    /// Bare path form; required by default.
+    Path(PathBuf),
+    /// Table form carrying an explicit `required` flag.
+    Detailed {
+        path: PathBuf,
+        #[serde(default = "default_true")]
+        required: bool,
+    },
+}
+
+impl SourceDir {
+    /// The directory this source points at.
+    pub fn path(&self) -> &Path {
+        match self {
+            SourceDir::Path(path) => path,
+            SourceDir::Detailed { path, .. } => path,
+        }
+    }
+
+    /// Whether a missing directory should abort (required) or warn (optional).
+    pub fn required(&self) -> bool {
+        match self {
+            SourceDir::Path(_) => true,
+            SourceDir::Detailed { required, .. } => *required,
+        }
+    }
+
+    /// Resolve a relative path against `base`, leaving absolute paths alone.
+    fn resolve_relative(&mut self, base: &Path) {
+        if self.path().is_relative() {
+            let joined = base.join(self.path());
+            match self {
+                SourceDir::Path(path) => *path = joined,
+                SourceDir::Detailed { path, .. } => *path = joined,
+            }
+        }
+    }
+}
+
+impl PythonConfig {
+    /// Primary source root (the first configured), used for display and for
+    /// rendering changed paths in a readable relative form.
+    pub fn primary_dir(&self) -> &Path {
+        self.source_dirs
+            .first()
+            .map(SourceDir::path)
+            .unwrap_or_else(|| Path::new("."))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TypeScriptConfig {
     /// Output file for generated TypeScript definitions
@@ -42,6 +188,115 @@ pub struct ApiConfig {
     /// Prefix for API routes
     #[serde(default)]
     pub prefix: String,
+    /// Transport/auth settings threaded into the generated fetch wrapper.
+    #[serde(default)]
+    pub transport: TransportConfig,
+}
+
+/// `credentials` mode passed to the generated `fetch` calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Credentials {
+    Omit,
+    SameOrigin,
+    Include,
+}
+
+impl Default for Credentials {
+    fn default() -> Self {
+        Credentials::SameOrigin
+    }
+}
+
+impl Credentials {
+    /// The literal value for the `fetch` `credentials` option.
+    pub fn as_fetch_value(self) -> &'static str {
+        match self {
+            Credentials::Omit => "omit",
+            Credentials::SameOrigin => "same-origin",
+            Credentials::Include => "include",
+        }
+    }
+}
+
+/// Exponential-backoff retry policy for the generated client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Maximum number of retries after the initial attempt (0 disables).
+    #[serde(default)]
+    pub max_retries: u32,
+    /// Base backoff in milliseconds, doubled each attempt.
+    #[serde(default = "default_backoff_ms")]
+    pub backoff_ms: u64,
+    /// HTTP status codes that trigger a retry.
+    #[serde(default)]
+    pub retry_on: Vec<u16>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_retries: 0,
+            backoff_ms: default_backoff_ms(),
+            retry_on: Vec::new(),
+        }
+    }
+}
+
+fn default_backoff_ms() -> u64 {
+    200
+}
+
+/// `[api.transport]`: headers, credentials, timeout, and retry policy.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TransportConfig {
+    /// Headers merged into every generated request.
+    #[serde(default)]
+    pub default_headers: HashMap<String, String>,
+    /// `fetch` credentials mode.
+    #[serde(default)]
+    pub credentials: Credentials,
+    /// Per-request timeout applied via an `AbortController`.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Retry policy for transient failures.
+    #[serde(default)]
+    pub retry: RetryConfig,
+}
+
+/// Deployment environment selected at generation time.
+///
+/// Parsed from an `ENVIRONMENT`/`POLYRPC_ENV` variable (or passed explicitly
+/// to [`load_config`]); selects the `[environments.<name>]` override table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    Development,
+    Production,
+}
+
+impl Environment {
+    /// The TOML table key this environment overrides from.
+    fn table_key(self) -> &'static str {
+        match self {
+            Environment::Development => "development",
+            Environment::Production => "production",
+        }
+    }
+
+    /// Parse an environment name, accepting the common short aliases.
+    fn parse(name: &str) -> Option<Environment> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "development" | "dev" | "local" => Some(Environment::Development),
+            "production" | "prod" => Some(Environment::Production),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.table_key())
+    }
 }
 
 fn default_include() -> Vec<String> {
@@ -61,15 +316,219 @@ fn default_base_url() -> String {
     "/api".to_string()
 }
 
+fn default_source_dir() -> PathBuf {
+    PathBuf::from("backend")
+}
+
+/// Fold the deprecated `source_dir` alias into the `source_dirs` list.
+///
+/// The alias, when present, takes the front position; if neither is set the
+/// historical `backend` default is used so existing configs keep working.
+fn resolve_source_dirs(alias: Option<PathBuf>, list: Option<Vec<SourceDir>>) -> Vec<SourceDir> {
+    let mut dirs = list.unwrap_or_default();
+    if let Some(dir) = alias {
+        dirs.insert(0, SourceDir::Path(dir));
+    }
+    if dirs.is_empty() {
+        dirs.push(SourceDir::Path(default_source_dir()));
+    }
+    dirs
+}
+
+fn default_output_file() -> PathBuf {
+    PathBuf::from("frontend/src/polyrpc.d.ts")
+}
+
 fn default_true() -> bool {
     true
 }
 
+/// An all-`Option` shadow of [`Config`] used as a single merge layer.
+///
+/// Every field is optional so that partial layers (the `[environments.<name>]`
+/// table, process env vars) can be deserialized and folded left-to-right with
+/// later `Some(..)` winning, before [`ConfigShadow::finalize`] fills the gaps.
+#[derive(Debug, Default, Deserialize)]
+struct ConfigShadow {
+    #[serde(default)]
+    python: PythonShadow,
+    #[serde(default)]
+    typescript: TypeScriptShadow,
+    #[serde(default)]
+    api: ApiShadow,
+    /// Per-environment partial overrides (only meaningful on the top-level layer).
+    #[serde(default)]
+    environments: HashMap<String, ConfigShadow>,
+    targets: Option<Vec<TargetConfig>>,
+    watch: Option<WatchConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PythonShadow {
+    /// Deprecated single-value alias, folded in front of `source_dirs`.
+    source_dir: Option<PathBuf>,
+    source_dirs: Option<Vec<SourceDir>>,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TypeScriptShadow {
+    output_file: Option<PathBuf>,
+    generate_client: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ApiShadow {
+    base_url: Option<String>,
+    prefix: Option<String>,
+    transport: Option<TransportConfig>,
+}
+
+impl PythonShadow {
+    fn merge(&mut self, other: PythonShadow) {
+        if other.source_dir.is_some() {
+            self.source_dir = other.source_dir;
+        }
+        if other.source_dirs.is_some() {
+            self.source_dirs = other.source_dirs;
+        }
+        if other.include.is_some() {
+            self.include = other.include;
+        }
+        if other.exclude.is_some() {
+            self.exclude = other.exclude;
+        }
+    }
+}
+
+impl TypeScriptShadow {
+    fn merge(&mut self, other: TypeScriptShadow) {
+        if other.output_file.is_some() {
+            self.output_file = other.output_file;
+        }
+        if other.generate_client.is_some() {
+            self.generate_client = other.generate_client;
+        }
+    }
+}
+
+impl ApiShadow {
+    fn merge(&mut self, other: ApiShadow) {
+        if other.base_url.is_some() {
+            self.base_url = other.base_url;
+        }
+        if other.prefix.is_some() {
+            self.prefix = other.prefix;
+        }
+        if other.transport.is_some() {
+            self.transport = other.transport;
+        }
+    }
+}
+
+impl ConfigShadow {
+    /// Fold another layer on top of this one; later `Some(..)` values win.
+    fn merge(&mut self, other: ConfigShadow) {
+        self.python.merge(other.python);
+        self.typescript.merge(other.typescript);
+        self.api.merge(other.api);
+        if other.targets.is_some() {
+            self.targets = other.targets;
+        }
+        if other.watch.is_some() {
+            self.watch = other.watch;
+        }
+        // `environments` is only consumed from the base layer, never merged.
+    }
+
+    /// Collapse to a concrete [`Config`], filling remaining gaps with defaults.
+    fn finalize(self) -> Config {
+        Config {
+            python: PythonConfig {
+                source_dirs: resolve_source_dirs(self.python.source_dir, self.python.source_dirs),
+                include: self.python.include.unwrap_or_else(default_include),
+                exclude: self.python.exclude.unwrap_or_else(default_exclude),
+            },
+            typescript: TypeScriptConfig {
+                output_file: self.typescript.output_file.unwrap_or_else(default_output_file),
+                generate_client: self.typescript.generate_client.unwrap_or(true),
+            },
+            api: ApiConfig {
+                base_url: self.api.base_url.unwrap_or_else(default_base_url),
+                prefix: self.api.prefix.unwrap_or_default(),
+                transport: self.api.transport.unwrap_or_default(),
+            },
+            watch: self.watch.unwrap_or_default(),
+            targets: self.targets.unwrap_or_default(),
+        }
+    }
+}
+
+/// Build the process-env override layer from `POLYRPC_<SECTION>_<FIELD>` vars.
+fn env_layer() -> ConfigShadow {
+    let mut layer = ConfigShadow::default();
+
+    if let Ok(v) = std::env::var("POLYRPC_PYTHON_SOURCE_DIR") {
+        layer.python.source_dir = Some(PathBuf::from(v));
+    }
+    if let Ok(v) = std::env::var("POLYRPC_TYPESCRIPT_OUTPUT_FILE") {
+        layer.typescript.output_file = Some(PathBuf::from(v));
+    }
+    if let Ok(v) = std::env::var("POLYRPC_TYPESCRIPT_GENERATE_CLIENT") {
+        layer.typescript.generate_client = Some(matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes"));
+    }
+    if let Ok(v) = std::env::var("POLYRPC_API_BASE_URL") {
+        layer.api.base_url = Some(v);
+    }
+    if let Ok(v) = std::env::var("POLYRPC_API_PREFIX") {
+        layer.api.prefix = Some(v);
+    }
+
+    layer
+}
+
+/// Load `.env.local` then `.env` from `dir`, setting any vars not already
+/// present in the process environment (`.env.local` wins over `.env`, and
+/// real env vars win over both).
+fn load_dotenv(dir: &Path) {
+    for name in [".env.local", ".env"] {
+        let path = dir.join(name);
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim();
+            let value = value.trim().trim_matches('"').trim_matches('\'');
+            if std::env::var_os(key).is_none() {
+                std::env::set_var(key, value);
+            }
+        }
+    }
+}
+
+/// Resolve the active environment from an explicit name or the
+/// `ENVIRONMENT`/`POLYRPC_ENV` variable, defaulting to development.
+fn resolve_environment(explicit: Option<&str>) -> Environment {
+    explicit
+        .and_then(Environment::parse)
+        .or_else(|| std::env::var("POLYRPC_ENV").ok().and_then(|v| Environment::parse(&v)))
+        .or_else(|| std::env::var("ENVIRONMENT").ok().and_then(|v| Environment::parse(&v)))
+        .unwrap_or(Environment::Development)
+}
+
 /// Create a default config file
 pub fn init_config() -> Result<()> {
     let config = Config {
         python: PythonConfig {
-            source_dir: PathBuf::from("backend"),
+            source_dirs: vec![SourceDir::Path(PathBuf::from("backend"))],
             include: default_include(),
             exclude: default_exclude(),
         },
@@ -80,21 +539,200 @@ pub fn init_config() -> Result<()> {
         api: ApiConfig {
             base_url: "/api".to_string(),
             prefix: String::new(),
+            transport: TransportConfig::default(),
         },
+        watch: WatchConfig::default(),
+        targets: Vec::new(),
     };
 
-    let toml_str = toml::to_string_pretty(&config)?;
+    let mut toml_str = toml::to_string_pretty(&config)?;
+    toml_str.push_str(ENVIRONMENTS_STUB);
     fs::write("polyrpc.toml", toml_str)?;
     Ok(())
 }
 
-/// Load config from file
-pub fn load_config(path: &Path) -> Result<Config> {
+/// Commented `[environments.production]` stub appended by `init_config`.
+const ENVIRONMENTS_STUB: &str = r#"
+# Per-environment overrides. Any field above may be overridden here and is
+# selected by ENVIRONMENT / POLYRPC_ENV (or `-e <name>`). Process env vars
+# named POLYRPC_<SECTION>_<FIELD> take final precedence.
+# [environments.production]
+# [environments.production.api]
+# base_url = "https://api.prod.example.com"
+"#;
+
+/// The config file name searched for during auto-discovery.
+const CONFIG_FILE_NAME: &str = "polyrpc.toml";
+
+impl Config {
+    /// Locate a `polyrpc.toml` without an explicit `-c` path.
+    ///
+    /// Starting at the current working directory, walk upward until a
+    /// `polyrpc.toml` is found or the filesystem root is reached, then fall
+    /// back to a user-level config in `dirs::config_dir()/polyrpc/polyrpc.toml`.
+    /// Returns the resolved path, or `None` when nothing exists anywhere.
+    pub fn discover() -> Option<PathBuf> {
+        let cwd = std::env::current_dir().ok()?;
+        for dir in cwd.ancestors() {
+            let candidate = dir.join(CONFIG_FILE_NAME);
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+
+        let user = dirs::config_dir()?.join("polyrpc").join(CONFIG_FILE_NAME);
+        user.is_file().then_some(user)
+    }
+
+    /// Discover and load a config, falling back to [`Config::default`] when
+    /// no file is found. Returns the resolved config path alongside the config
+    /// so callers can report which file (if any) was used.
+    pub fn load_or_default(environment: Option<&str>) -> Result<(Config, Option<PathBuf>)> {
+        match Self::discover() {
+            Some(path) => {
+                let config = load_config(&path, environment)?;
+                Ok((config, Some(path)))
+            }
+            None => Ok((Config::default(), None)),
+        }
+    }
+
+    /// Resolve every `python.source_dirs` entry and every `output_file` relative to
+    /// the discovered config file's directory rather than the current directory.
+    /// Absolute paths are left untouched.
+    pub fn resolve_relative_to(&mut self, config_dir: &Path) {
+        for source in &mut self.python.source_dirs {
+            source.resolve_relative(config_dir);
+        }
+        if self.typescript.output_file.is_relative() {
+            self.typescript.output_file = config_dir.join(&self.typescript.output_file);
+        }
+        for target in &mut self.targets {
+            if target.output_file.is_relative() {
+                target.output_file = config_dir.join(&target.output_file);
+            }
+        }
+    }
+
+    /// The effective list of generation targets.
+    ///
+    /// Falls back to a single implicit target built from the top-level
+    /// `[typescript]`/`[api]` tables when no `[[targets]]` are declared, so the
+    /// single-output form keeps working unchanged. Per-target `include`/`exclude`
+    /// default to `python.include`/`python.exclude` when not overridden.
+    pub fn resolved_targets(&self) -> Vec<ResolvedTarget> {
+        if self.targets.is_empty() {
+            return vec![ResolvedTarget {
+                output_file: self.typescript.output_file.clone(),
+                include: self.python.include.clone(),
+                exclude: self.python.exclude.clone(),
+                generate_client: self.typescript.generate_client,
+                base_url: self.api.base_url.clone(),
+                prefix: self.api.prefix.clone(),
+                transport: self.api.transport.clone(),
+            }];
+        }
+
+        self.targets
+            .iter()
+            .map(|t| ResolvedTarget {
+                output_file: t.output_file.clone(),
+                include: t.include.clone().unwrap_or_else(|| self.python.include.clone()),
+                exclude: t.exclude.clone().unwrap_or_else(|| self.python.exclude.clone()),
+                generate_client: t.generate_client,
+                base_url: t.api.base_url.clone(),
+                prefix: t.api.prefix.clone(),
+                transport: t.api.transport.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        ConfigShadow::default().finalize()
+    }
+}
+
+/// Load config from file, layering environment and process-env overrides.
+///
+/// Resolution precedence (low to high): struct defaults < top-level toml <
+/// `[environments.<selected>]` < `POLYRPC_<SECTION>_<FIELD>` env vars.
+pub fn load_config(path: &Path, environment: Option<&str>) -> Result<Config> {
     let content = fs::read_to_string(path)
         .with_context(|| format!("Failed to read config file: {}", path.display()))?;
-    
-    let config: Config = toml::from_str(&content)
+
+    let config_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    load_dotenv(config_dir);
+
+    let mut base: ConfigShadow = toml::from_str(&content)
         .with_context(|| "Failed to parse config file")?;
-    
-    Ok(config)
+
+    // Peel the selected environment table off the base layer before folding.
+    let env = resolve_environment(environment);
+    let env_override = base.environments.remove(env.table_key());
+
+    let mut merged = ConfigShadow::default();
+    merged.merge(base);
+    if let Some(over) = env_override {
+        merged.merge(over);
+    }
+    merged.merge(env_layer());
+
+    Ok(merged.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_environment_parse_aliases() {
+        assert_eq!(Environment::parse("prod"), Some(Environment::Production));
+        assert_eq!(Environment::parse("LOCAL"), Some(Environment::Development));
+        assert_eq!(Environment::parse("staging"), None);
+    }
+
+    #[test]
+    fn test_source_dir_alias_folds_into_source_dirs() {
+        let toml = r#"
+[python]
+source_dir = "legacy"
+source_dirs = [{ path = "pkg_a" }, { path = "pkg_b", required = false }]
+
+[typescript]
+output_file = "out.ts"
+"#;
+        let config = toml::from_str::<ConfigShadow>(toml).unwrap().finalize();
+        let dirs = &config.python.source_dirs;
+        // Deprecated alias takes the front position, required by default.
+        assert_eq!(dirs[0].path(), Path::new("legacy"));
+        assert!(dirs[0].required());
+        assert_eq!(dirs[1].path(), Path::new("pkg_a"));
+        assert!(!dirs[2].required());
+    }
+
+    #[test]
+    fn test_environment_override_wins_over_top_level() {
+        let toml = r#"
+[python]
+source_dir = "backend"
+
+[typescript]
+output_file = "out.ts"
+
+[api]
+base_url = "/api"
+
+[environments.production.api]
+base_url = "https://api.prod.example.com"
+"#;
+        let mut base: ConfigShadow = toml::from_str(toml).unwrap();
+        let over = base.environments.remove("production");
+        let mut merged = ConfigShadow::default();
+        merged.merge(base);
+        merged.merge(over.unwrap());
+        let config = merged.finalize();
+        assert_eq!(config.api.base_url, "https://api.prod.example.com");
+    }
 }
@@ -0,0 +1,104 @@
+//! Diagnostics collected during code generation.
+//!
+//! Generation never aborts on a single unresolved type; instead each problem is
+//! recorded here with enough context (model+field or route+function, and a
+//! source span where the parser can supply one) to locate it, then surfaced as
+//! a ranked report — errors before warnings.
+
+use colored::Colorize;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A location in a Python source file, when the parser can supply one.
+#[derive(Debug, Clone)]
+pub struct SourceSpan {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file, self.line, self.column),
+            None => write!(f, "{}:{}", self.line, self.column),
+        }
+    }
+}
+
+/// A single generation problem.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub span: Option<SourceSpan>,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.severity {
+            Severity::Error => "error".red(),
+            Severity::Warning => "warning".yellow(),
+        };
+        match &self.span {
+            Some(span) => write!(f, "{} [{}]: {}", label, span, self.message),
+            None => write!(f, "{}: {}", label, self.message),
+        }
+    }
+}
+
+/// Accumulates diagnostics across a generation run.
+#[derive(Debug, Default)]
+pub struct DiagnosticCollector {
+    items: Vec<Diagnostic>,
+}
+
+impl DiagnosticCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a warning (generation degrades gracefully to `unknown`).
+    pub fn warn(&mut self, message: impl Into<String>, span: Option<SourceSpan>) {
+        self.items.push(Diagnostic {
+            severity: Severity::Warning,
+            message: message.into(),
+            span,
+        });
+    }
+
+    /// Record a hard error (e.g. a dangling reference that cannot be emitted).
+    pub fn error(&mut self, message: impl Into<String>, span: Option<SourceSpan>) {
+        self.items.push(Diagnostic {
+            severity: Severity::Error,
+            message: message.into(),
+            span,
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Consume the collector, returning diagnostics ranked errors-first.
+    pub fn into_ranked(self) -> Vec<Diagnostic> {
+        let mut items = self.items;
+        items.sort_by(|a, b| match (a.severity, b.severity) {
+            (Severity::Error, Severity::Warning) => Ordering::Less,
+            (Severity::Warning, Severity::Error) => Ordering::Greater,
+            _ => Ordering::Equal,
+        });
+        items
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Converts parsed Python types into TypeScript definitions and a type-safe client.
 
+use crate::config::TransportConfig;
+use crate::diagnostics::{Diagnostic, DiagnosticCollector};
 use crate::parser::{ApiRoute, ExtractedTypes, PyEnum, PyType, PydanticModel};
 use anyhow::{Context, Result};
 use std::collections::HashMap;
@@ -9,7 +11,7 @@ use std::fs;
 use std::path::Path;
 
 /// Generate a TypeScript enum from a Python Enum
-fn generate_enum(py_enum: &PyEnum) -> String {
+fn generate_enum(py_enum: &PyEnum, _diags: &mut DiagnosticCollector) -> String {
     let mut output = String::new();
     
     // Add docstring as JSDoc
@@ -37,9 +39,13 @@ fn generate_enum(py_enum: &PyEnum) -> String {
 }
 
 /// Generate a TypeScript interface from a Pydantic model
-fn generate_interface(model: &PydanticModel) -> String {
+fn generate_interface(
+    model: &PydanticModel,
+    types: &ExtractedTypes,
+    diags: &mut DiagnosticCollector,
+) -> String {
     let mut output = String::new();
-    
+
     // Add docstring as JSDoc
     if let Some(doc) = &model.docstring {
         output.push_str("/**\n");
@@ -48,21 +54,28 @@ fn generate_interface(model: &PydanticModel) -> String {
         }
         output.push_str(" */\n");
     }
-    
-    output.push_str(&format!("export interface {} {{\n", model.name));
-    
+
+    // Emit declared type parameters: `export interface Page<T> { ... }`.
+    let type_params = if model.type_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", model.type_params.join(", "))
+    };
+    output.push_str(&format!("export interface {}{} {{\n", model.name, type_params));
+
     for field in &model.fields {
         // Add field description as JSDoc if present
         if let Some(desc) = &field.description {
             output.push_str(&format!("  /** {} */\n", desc));
         }
-        
-        let ts_type = py_type_to_ts(&field.py_type);
+
+        let ctx = format!("{}.{}", model.name, field.name);
+        let ts_type = py_type_to_ts_checked(&field.py_type, types, &model.type_params, &ctx, diags);
         let optional_marker = if field.optional { "?" } else { "" };
-        
+
         output.push_str(&format!("  {}{}: {};\n", field.name, optional_marker, ts_type));
     }
-    
+
     output.push_str("}\n");
     output
 }
@@ -89,9 +102,9 @@ fn py_type_to_ts(py_type: &PyType) -> String {
         PyType::Bytes => "string".to_string(),     // Base64 encoded
         
         // Collection types
-        PyType::List(inner) => format!("{}[]", py_type_to_ts(inner)),
-        PyType::Set(inner) => format!("{}[]", py_type_to_ts(inner)),  // Sets become arrays
-        PyType::FrozenSet(inner) => format!("readonly {}[]", py_type_to_ts(inner)),  // Immutable
+        PyType::List(inner) => format!("{}[]", wrap_union(&py_type_to_ts(inner))),
+        PyType::Set(inner) => format!("{}[]", wrap_union(&py_type_to_ts(inner))),  // Sets become arrays
+        PyType::FrozenSet(inner) => format!("readonly {}[]", wrap_union(&py_type_to_ts(inner))),  // Immutable
         PyType::Tuple(types) => {
             if types.is_empty() {
                 "[]".to_string()
@@ -111,8 +124,10 @@ fn py_type_to_ts(py_type: &PyType) -> String {
             ts_types.join(" | ")
         }
         PyType::Literal(values) => {
-            let quoted: Vec<String> = values.iter().map(|v| format!("\"{}\"", v)).collect();
-            quoted.join(" | ")
+            // String members stay quoted; numeric and boolean members are
+            // emitted bare (see [`literal_member`]).
+            let arms: Vec<String> = values.iter().map(|v| literal_member(v)).collect();
+            arms.join(" | ")
         }
         
         // Generic types
@@ -130,108 +145,419 @@ fn py_type_to_ts(py_type: &PyType) -> String {
     }
 }
 
-/// Convert a Python type string (like "List[User]") to TypeScript syntax
-fn convert_python_type_string(py_type: &str) -> String {
-    let py_type = py_type.trim();
-    
-    // Handle List[X] -> X[]
-    if py_type.starts_with("List[") && py_type.ends_with(']') {
-        let inner = &py_type[5..py_type.len() - 1];
-        return format!("{}[]", convert_python_type_string(inner));
+/// Like [`py_type_to_ts`], but records a diagnostic whenever a type cannot be
+/// resolved to a real primitive, model, or enum. Generation still degrades
+/// gracefully to `unknown`; `ctx` describes where the type came from (e.g.
+/// `User.address` or `list_users query param`).
+fn py_type_to_ts_checked(
+    py_type: &PyType,
+    types: &ExtractedTypes,
+    declared: &[String],
+    ctx: &str,
+    diags: &mut DiagnosticCollector,
+) -> String {
+    match py_type {
+        PyType::List(inner) | PyType::Set(inner) => {
+            // Both lists and sets serialize to JSON arrays. Parenthesize a union
+            // element so `list[int | None]` emits `(number | null)[]`, not the
+            // mis-bound `number | null[]`.
+            format!(
+                "{}[]",
+                wrap_union(&py_type_to_ts_checked(inner, types, declared, ctx, diags))
+            )
+        }
+        PyType::FrozenSet(inner) => {
+            format!(
+                "readonly {}[]",
+                wrap_union(&py_type_to_ts_checked(inner, types, declared, ctx, diags))
+            )
+        }
+        PyType::Tuple(members) => {
+            if members.is_empty() {
+                "[]".to_string()
+            } else {
+                let parts: Vec<String> = members
+                    .iter()
+                    .map(|m| py_type_to_ts_checked(m, types, declared, ctx, diags))
+                    .collect();
+                format!("[{}]", parts.join(", "))
+            }
+        }
+        PyType::Dict(key, value) => format!(
+            "Record<{}, {}>",
+            py_type_to_ts_checked(key, types, declared, ctx, diags),
+            py_type_to_ts_checked(value, types, declared, ctx, diags)
+        ),
+        PyType::Optional(inner) => {
+            format!("{} | null", py_type_to_ts_checked(inner, types, declared, ctx, diags))
+        }
+        PyType::Union(members) => members
+            .iter()
+            .map(|m| py_type_to_ts_checked(m, types, declared, ctx, diags))
+            .collect::<Vec<_>>()
+            .join(" | "),
+        PyType::GenericType(base, params) => {
+            // Reference validity (including the base) is handled by the
+            // resolver pass; here we only recurse to build `Name<Args>`.
+            let param_strs: Vec<String> = params
+                .iter()
+                .map(|p| py_type_to_ts_checked(p, types, declared, ctx, diags))
+                .collect();
+            format!("{}<{}>", base, param_strs.join(", "))
+        }
+        PyType::Reference(name) => name.clone(),
+        PyType::Generic(name) => {
+            // A type variable declared by the enclosing generic model is legal;
+            // an unbound one leaks into output and is worth a diagnostic.
+            if !declared.iter().any(|p| p == name) {
+                diags.warn(
+                    format!("unbound type variable '{name}' in {ctx}"),
+                    None,
+                );
+            }
+            name.clone()
+        }
+        PyType::Unknown(name) => {
+            diags.warn(
+                format!("unknown type '{name}' in {ctx}, emitting `unknown`"),
+                None,
+            );
+            "unknown".to_string()
+        }
+        // Everything else resolves unambiguously; defer to the pure mapping.
+        other => py_type_to_ts(other),
     }
-    
-    // Handle list[X] -> X[]
-    if py_type.starts_with("list[") && py_type.ends_with(']') {
-        let inner = &py_type[5..py_type.len() - 1];
-        return format!("{}[]", convert_python_type_string(inner));
+}
+
+/// A registry mapping scalar Python type names to target-language types.
+///
+/// Seeded with sensible defaults (including the rich scalars pyo3 bridges), but
+/// users can register custom domain types before codegen (e.g. `Money` →
+/// `string`) without patching the crate. The recursive container logic lives in
+/// [`TypeMapper::convert`] and calls back into the registry for leaf types.
+#[derive(Debug, Clone)]
+pub struct TypeMapper {
+    scalars: HashMap<String, String>,
+}
+
+impl Default for TypeMapper {
+    fn default() -> Self {
+        let mut scalars = HashMap::new();
+        let mut set = |py: &str, ts: &str| {
+            scalars.insert(py.to_string(), ts.to_string());
+        };
+
+        // Primitives.
+        set("str", "string");
+        set("int", "number");
+        set("float", "number");
+        set("bool", "boolean");
+        set("None", "null");
+        set("dict", "Record<string, unknown>");
+        // Arbitrary-precision ints annotated as `bigint`.
+        set("bigint", "bigint");
+
+        // Date/time scalars all serialize to strings.
+        for name in ["datetime", "DateTime", "date", "Date", "time", "Time", "timedelta", "TimeDelta"] {
+            set(name, "string");
+        }
+
+        // Special scalars bridged by pyo3.
+        set("UUID", "string");
+        set("uuid", "string");
+        set("Decimal", "string");
+        set("decimal", "string");
+        for name in ["bytes", "Bytes", "bytearray", "memoryview"] {
+            set(name, "Uint8Array");
+        }
+        set("complex", "{ real: number; imag: number }");
+
+        TypeMapper { scalars }
     }
-    
-    // Handle Set[X] -> X[]
-    if py_type.starts_with("Set[") && py_type.ends_with(']') {
-        let inner = &py_type[4..py_type.len() - 1];
-        return format!("{}[]", convert_python_type_string(inner));
+}
+
+impl TypeMapper {
+    /// Register (or override) a scalar mapping.
+    pub fn register(&mut self, py_type: impl Into<String>, ts_type: impl Into<String>) {
+        self.scalars.insert(py_type.into(), ts_type.into());
+    }
+
+    /// Convert a Python type string (like "List[User]") to TypeScript syntax.
+    pub fn convert(&self, py_type: &str) -> String {
+        let py_type = py_type.trim();
+
+        // Handle List[X] / list[X] -> X[]
+        for prefix in ["List[", "list["] {
+            if let Some(inner) = strip_generic(py_type, prefix) {
+                return format!("{}[]", wrap_union(&self.convert(inner)));
+            }
+        }
+
+        // Handle Set[X] / set[X] -> X[]
+        for prefix in ["Set[", "set["] {
+            if let Some(inner) = strip_generic(py_type, prefix) {
+                return format!("{}[]", wrap_union(&self.convert(inner)));
+            }
+        }
+
+        // Handle FrozenSet[X] / frozenset[X] -> readonly X[]
+        for prefix in ["FrozenSet[", "frozenset["] {
+            if let Some(inner) = strip_generic(py_type, prefix) {
+                return format!("readonly {}[]", wrap_union(&self.convert(inner)));
+            }
+        }
+
+        // Handle Tuple[A, B, C] -> [A, B, C], plus the homogeneous variadic
+        // form `Tuple[X, ...]` -> `X[]`. The splitter is bracket-aware, so
+        // nested tuples recurse correctly via `self.convert`.
+        for prefix in ["Tuple[", "tuple["] {
+            if let Some(inner) = strip_generic(py_type, prefix) {
+                let args = split_type_args(inner);
+                if args.len() == 2 && args[1] == "..." {
+                    return format!("{}[]", wrap_union(&self.convert(&args[0])));
+                }
+                let parts: Vec<String> = args.iter().map(|p| self.convert(p)).collect();
+                return format!("[{}]", parts.join(", "));
+            }
+        }
+
+        // Handle Optional[X] -> X | null
+        if let Some(inner) = strip_generic(py_type, "Optional[") {
+            return format!("{} | null", self.convert(inner));
+        }
+
+        // Handle Union[A, B, ...] -> A | B, recursing into each arm and
+        // respecting nested brackets so `Union[Dict[str, int], str]` does not
+        // split inside the `Dict`. Identical arms are deduplicated.
+        if let Some(inner) = strip_generic(py_type, "Union[") {
+            let mut arms: Vec<String> = Vec::new();
+            for part in split_type_args(inner) {
+                let converted = self.convert(&part);
+                if !arms.contains(&converted) {
+                    arms.push(converted);
+                }
+            }
+            return arms.join(" | ");
+        }
+
+        // Handle Dict[K, V] and its aliases. A string/number key yields an
+        // idiomatic `Record<K, V>`; any other key type falls back to an index
+        // signature since TypeScript only indexes by string/number/symbol.
+        for prefix in ["Dict[", "dict[", "DefaultDict[", "OrderedDict[", "Mapping["] {
+            if let Some(inner) = strip_generic(py_type, prefix) {
+                let parts = split_type_args(inner);
+                if parts.len() == 2 {
+                    let key = self.convert(&parts[0]);
+                    let value = self.convert(&parts[1]);
+                    return if key == "string" || key == "number" {
+                        format!("Record<{key}, {value}>")
+                    } else {
+                        format!("{{ [key: string]: {value} }}")
+                    };
+                }
+            }
+        }
+
+        // Handle Literal["a", "b", 3] -> "a" | "b" | 3. String members stay
+        // quoted; numeric and boolean members are emitted bare so the client
+        // gets an exhaustive literal union discriminant.
+        if let Some(inner) = strip_generic(py_type, "Literal[") {
+            let arms: Vec<String> = split_type_args(inner)
+                .iter()
+                .map(|a| literal_member(a))
+                .collect();
+            return arms.join(" | ");
+        }
+
+        // Handle a user-defined generic application like Page[User] -> Page<User>.
+        // Known containers are handled above, so anything reaching here with a
+        // capitalized base is treated as a parameterized model.
+        if py_type.ends_with(']') {
+            if let Some(bracket) = py_type.find('[') {
+                let base = &py_type[..bracket];
+                if base.chars().next().is_some_and(|c| c.is_ascii_uppercase()) {
+                    let inner = &py_type[bracket + 1..py_type.len() - 1];
+                    let args: Vec<String> =
+                        split_type_args(inner).iter().map(|a| self.convert(a)).collect();
+                    return format!("{}<{}>", base, args.join(", "));
+                }
+            }
+        }
+
+        // Leaf type: consult the registry, else pass through verbatim.
+        self.scalars
+            .get(py_type)
+            .cloned()
+            .unwrap_or_else(|| py_type.to_string())
     }
-    if py_type.starts_with("set[") && py_type.ends_with(']') {
-        let inner = &py_type[4..py_type.len() - 1];
-        return format!("{}[]", convert_python_type_string(inner));
+}
+
+/// Render a single `Literal[...]` member as a TypeScript literal. String
+/// members (quoted in Python) become double-quoted TS strings; numeric and
+/// boolean members are emitted unquoted, translating Python's `True`/`False`.
+fn literal_member(member: &str) -> String {
+    let member = member.trim();
+    if let Some(stripped) = member
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| member.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+    {
+        return format!("\"{stripped}\"");
     }
-    
-    // Handle FrozenSet[X] -> readonly X[]
-    if py_type.starts_with("FrozenSet[") && py_type.ends_with(']') {
-        let inner = &py_type[10..py_type.len() - 1];
-        return format!("readonly {}[]", convert_python_type_string(inner));
+    match member {
+        "True" => "true".to_string(),
+        "False" => "false".to_string(),
+        other => other.to_string(),
     }
-    if py_type.starts_with("frozenset[") && py_type.ends_with(']') {
-        let inner = &py_type[10..py_type.len() - 1];
-        return format!("readonly {}[]", convert_python_type_string(inner));
+}
+
+/// Which language a Python type annotation is converted into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetLang {
+    /// TypeScript type syntax (`User[]`, `Record<string, number>`).
+    TypeScript,
+    /// Idiomatic Rust/serde syntax (`Vec<User>`, `HashMap<String, i64>`).
+    Rust,
+}
+
+impl TargetLang {
+    /// Convert a Python type string for this target language.
+    pub fn convert(self, py_type: &str) -> String {
+        match self {
+            TargetLang::TypeScript => convert_python_type_string(py_type),
+            TargetLang::Rust => convert_python_type_to_rust(py_type),
+        }
     }
-    
-    // Handle Tuple[A, B, C] -> [A, B, C]
-    if py_type.starts_with("Tuple[") && py_type.ends_with(']') {
-        let inner = &py_type[6..py_type.len() - 1];
-        let parts: Vec<String> = split_type_args(inner)
-            .iter()
-            .map(|p| convert_python_type_string(p))
-            .collect();
-        return format!("[{}]", parts.join(", "));
+}
+
+/// Convert a Python type string (like `List[User]`) into idiomatic Rust/serde
+/// syntax. Shares the bracket-aware splitter with the TypeScript backend; only
+/// the container shapes and leaf scalar mappings differ.
+fn convert_python_type_to_rust(py_type: &str) -> String {
+    let py_type = py_type.trim();
+
+    for prefix in ["List[", "list["] {
+        if let Some(inner) = strip_generic(py_type, prefix) {
+            return format!("Vec<{}>", convert_python_type_to_rust(inner));
+        }
     }
-    if py_type.starts_with("tuple[") && py_type.ends_with(']') {
-        let inner = &py_type[6..py_type.len() - 1];
-        let parts: Vec<String> = split_type_args(inner)
-            .iter()
-            .map(|p| convert_python_type_string(p))
-            .collect();
-        return format!("[{}]", parts.join(", "));
+    for prefix in ["Set[", "set["] {
+        if let Some(inner) = strip_generic(py_type, prefix) {
+            return format!("HashSet<{}>", convert_python_type_to_rust(inner));
+        }
     }
-    
-    // Handle Optional[X] -> X | null
-    if py_type.starts_with("Optional[") && py_type.ends_with(']') {
-        let inner = &py_type[9..py_type.len() - 1];
-        return format!("{} | null", convert_python_type_string(inner));
+    for prefix in ["FrozenSet[", "frozenset["] {
+        if let Some(inner) = strip_generic(py_type, prefix) {
+            return format!("BTreeSet<{}>", convert_python_type_to_rust(inner));
+        }
     }
-    
-    // Handle Dict[K, V] -> Record<K, V>
-    if py_type.starts_with("Dict[") && py_type.ends_with(']') {
-        let inner = &py_type[5..py_type.len() - 1];
-        let parts = split_type_args(inner);
-        if parts.len() == 2 {
-            return format!(
-                "Record<{}, {}>",
-                convert_python_type_string(&parts[0]),
-                convert_python_type_string(&parts[1])
-            );
+    for prefix in ["Dict[", "dict[", "DefaultDict[", "OrderedDict[", "Mapping["] {
+        if let Some(inner) = strip_generic(py_type, prefix) {
+            let parts = split_type_args(inner);
+            if parts.len() == 2 {
+                return format!(
+                    "HashMap<{}, {}>",
+                    convert_python_type_to_rust(&parts[0]),
+                    convert_python_type_to_rust(&parts[1])
+                );
+            }
         }
     }
-    if py_type.starts_with("dict[") && py_type.ends_with(']') {
-        let inner = &py_type[5..py_type.len() - 1];
+    for prefix in ["Tuple[", "tuple["] {
+        if let Some(inner) = strip_generic(py_type, prefix) {
+            let args = split_type_args(inner);
+            if args.len() == 2 && args[1] == "..." {
+                return format!("Vec<{}>", convert_python_type_to_rust(&args[0]));
+            }
+            let parts: Vec<String> =
+                args.iter().map(|p| convert_python_type_to_rust(p)).collect();
+            return format!("({})", parts.join(", "));
+        }
+    }
+    if let Some(inner) = strip_generic(py_type, "Optional[") {
+        return format!("Option<{}>", convert_python_type_to_rust(inner));
+    }
+    if let Some(inner) = strip_generic(py_type, "Union[") {
         let parts = split_type_args(inner);
-        if parts.len() == 2 {
-            return format!(
-                "Record<{}, {}>",
-                convert_python_type_string(&parts[0]),
-                convert_python_type_string(&parts[1])
-            );
+        // `Union[X, None]` is really `Option<X>`; richer unions have no
+        // anonymous Rust equivalent, so fall back to a dynamic JSON value.
+        let non_none: Vec<&String> = parts.iter().filter(|p| p.as_str() != "None").collect();
+        if parts.len() - non_none.len() == 1 && non_none.len() == 1 {
+            return format!("Option<{}>", convert_python_type_to_rust(non_none[0]));
         }
+        return "serde_json::Value".to_string();
     }
-    
-    // Handle basic types
+
+    // User-defined generic application: `Page[User]` -> `Page<User>`.
+    if py_type.ends_with(']') {
+        if let Some(bracket) = py_type.find('[') {
+            let base = &py_type[..bracket];
+            if base.chars().next().is_some_and(|c| c.is_ascii_uppercase()) {
+                let inner = &py_type[bracket + 1..py_type.len() - 1];
+                let args: Vec<String> = split_type_args(inner)
+                    .iter()
+                    .map(|a| convert_python_type_to_rust(a))
+                    .collect();
+                return format!("{}<{}>", base, args.join(", "));
+            }
+        }
+    }
+
+    // Leaf scalar mappings; unknown names pass through as-is (model refs).
     match py_type {
-        "str" => "string".to_string(),
-        "int" | "float" => "number".to_string(),
-        "bool" => "boolean".to_string(),
-        "None" => "null".to_string(),
-        "dict" => "Record<string, unknown>".to_string(),
-        // Date/Time types
-        "datetime" | "DateTime" => "string".to_string(),
-        "date" | "Date" => "string".to_string(),
-        "time" | "Time" => "string".to_string(),
-        "timedelta" | "TimeDelta" => "number".to_string(),
-        // Special types
-        "UUID" | "uuid" => "string".to_string(),
-        "Decimal" | "decimal" => "string".to_string(),
-        "bytes" | "Bytes" => "string".to_string(),
-        _ => py_type.to_string(),
+        "str" | "String" => "String",
+        "int" | "Integer" => "i64",
+        "float" | "Float" => "f64",
+        "bool" | "Boolean" => "bool",
+        "None" | "NoneType" => "()",
+        "Any" => "serde_json::Value",
+        "bytes" | "Bytes" | "bytearray" => "Vec<u8>",
+        "UUID" | "uuid" => "uuid::Uuid",
+        "datetime" | "DateTime" => "chrono::DateTime<Utc>",
+        "date" | "Date" => "chrono::NaiveDate",
+        "time" | "Time" => "chrono::NaiveTime",
+        "Decimal" | "decimal" => "rust_decimal::Decimal",
+        other => other,
+    }
+    .to_string()
+}
+
+/// Strip a generic wrapper `prefix` (e.g. `"List["`) and its trailing `]`,
+/// returning the inner type string.
+fn strip_generic<'a>(py_type: &'a str, prefix: &str) -> Option<&'a str> {
+    py_type
+        .strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix(']'))
+}
+
+/// Convert a Python type string (like "List[User]") to TypeScript syntax, using
+/// the default [`TypeMapper`] registry.
+fn convert_python_type_string(py_type: &str) -> String {
+    TypeMapper::default().convert(py_type)
+}
+
+/// Parenthesize a converted type when it is a top-level union, so that it
+/// composes correctly as an array element: `number | null` becomes
+/// `(number | null)` before `[]` is appended. Unions nested inside brackets
+/// or angle brackets (e.g. `Record<string, A | B>`) are left untouched.
+fn wrap_union(ts_type: &str) -> String {
+    let mut depth = 0i32;
+    let bytes = ts_type.as_bytes();
+    for (i, c) in ts_type.char_indices() {
+        match c {
+            '[' | '<' | '(' | '{' => depth += 1,
+            ']' | '>' | ')' | '}' => depth -= 1,
+            '|' if depth == 0
+                && i > 0
+                && bytes[i - 1] == b' '
+                && bytes.get(i + 1) == Some(&b' ') =>
+            {
+                return format!("({ts_type})");
+            }
+            _ => {}
+        }
     }
+    ts_type.to_string()
 }
 
 /// Split type arguments respecting nested brackets
@@ -261,26 +587,31 @@ fn split_type_args(args: &str) -> Vec<String> {
 
 
 /// Generate input type for a route (includes path params, query params, and body)
-fn generate_route_input_type(route: &ApiRoute) -> String {
+fn generate_route_input_type(
+    route: &ApiRoute,
+    types: &ExtractedTypes,
+    diags: &mut DiagnosticCollector,
+) -> String {
     let mut parts = Vec::new();
-    
+
     // Path parameters
     if !route.path_params.is_empty() {
         let params: Vec<String> = route
             .path_params
             .iter()
-            .map(|p| format!("{}: string | number", p))
+            .map(|p| format!("{}: string | number", p.name))
             .collect();
         parts.push(format!("{{ {} }}", params.join("; ")));
     }
-    
+
     // Query parameters
     if !route.query_params.is_empty() {
         let params: Vec<String> = route
             .query_params
             .iter()
             .map(|p| {
-                let ts_type = py_type_to_ts(&p.py_type);
+                let ctx = format!("{} query param `{}`", route.function_name, p.name);
+                let ts_type = py_type_to_ts_checked(&p.py_type, types, &[], &ctx, diags);
                 let optional = if p.optional { "?" } else { "" };
                 format!("{}{}: {}", p.name, optional, ts_type)
             })
@@ -302,8 +633,131 @@ fn generate_route_input_type(route: &ApiRoute) -> String {
     }
 }
 
+/// Emit the module-level transport constants (headers, credentials, timeout,
+/// retry policy) shared by the generated request helper.
+fn transport_constants(transport: &TransportConfig) -> String {
+    let mut out = String::new();
+
+    let mut headers: Vec<(&String, &String)> = transport.default_headers.iter().collect();
+    headers.sort_by(|a, b| a.0.cmp(b.0));
+    let header_entries: Vec<String> = headers
+        .iter()
+        .map(|(k, v)| format!("  '{}': '{}',", k, v))
+        .collect();
+    if header_entries.is_empty() {
+        out.push_str("const DEFAULT_HEADERS: Record<string, string> = {};\n");
+    } else {
+        out.push_str("const DEFAULT_HEADERS: Record<string, string> = {\n");
+        out.push_str(&header_entries.join("\n"));
+        out.push_str("\n};\n");
+    }
+
+    out.push_str(&format!(
+        "const CREDENTIALS: RequestCredentials = '{}';\n",
+        transport.credentials.as_fetch_value()
+    ));
+    out.push_str(&format!(
+        "const TIMEOUT_MS = {};\n",
+        transport.timeout_ms.map(|t| t.to_string()).unwrap_or_else(|| "0".to_string())
+    ));
+    out.push_str(&format!("const MAX_RETRIES = {};\n", transport.retry.max_retries));
+    out.push_str(&format!("const BACKOFF_MS = {};\n", transport.retry.backoff_ms));
+    let retry_on: Vec<String> = transport.retry.retry_on.iter().map(|c| c.to_string()).collect();
+    out.push_str(&format!("const RETRY_ON: number[] = [{}];\n", retry_on.join(", ")));
+
+    out
+}
+
+/// Emit the `request` helper, applying default headers, an `AbortController`
+/// timeout, and exponential-backoff retries on the configured status codes.
+fn generate_request_helper(_transport: &TransportConfig) -> String {
+    r#"async function request<TOutput>(
+  method: string,
+  path: string,
+  body?: unknown,
+  query?: Record<string, unknown>
+): Promise<TOutput> {
+  let url = `${BASE_URL}${path}`;
+
+  // Add query parameters
+  if (query) {
+    const params = new URLSearchParams();
+    for (const [key, value] of Object.entries(query)) {
+      if (value !== undefined && value !== null) {
+        params.append(key, String(value));
+      }
+    }
+    const queryString = params.toString();
+    if (queryString) {
+      url += `?${queryString}`;
+    }
+  }
+
+  let attempt = 0;
+  // Retries are inclusive of the initial attempt plus MAX_RETRIES.
+  for (;;) {
+    const controller = TIMEOUT_MS > 0 ? new AbortController() : undefined;
+    const timer = controller
+      ? setTimeout(() => controller.abort(), TIMEOUT_MS)
+      : undefined;
+
+    try {
+      const response = await fetch(url, {
+        method,
+        credentials: CREDENTIALS,
+        headers: {
+          'Content-Type': 'application/json',
+          ...DEFAULT_HEADERS,
+        },
+        body: body ? JSON.stringify(body) : undefined,
+        signal: controller?.signal,
+      });
+
+      if (!response.ok) {
+        if (RETRY_ON.includes(response.status) && attempt < MAX_RETRIES) {
+          await new Promise((r) => setTimeout(r, BACKOFF_MS * 2 ** attempt));
+          attempt++;
+          continue;
+        }
+        let errorData: unknown;
+        try {
+          errorData = await response.json();
+        } catch {
+          errorData = await response.text();
+        }
+        throw new PolyRPCError(
+          `Request failed: ${response.status} ${response.statusText}`,
+          response.status,
+          errorData
+        );
+      }
+
+      return response.json();
+    } catch (err) {
+      if (!(err instanceof PolyRPCError) && attempt < MAX_RETRIES) {
+        await new Promise((r) => setTimeout(r, BACKOFF_MS * 2 ** attempt));
+        attempt++;
+        continue;
+      }
+      throw err;
+    } finally {
+      if (timer) clearTimeout(timer);
+    }
+  }
+}
+
+"#
+    .to_string()
+}
+
 /// Generate the complete client implementation (polyrpc.ts)
-fn generate_client_implementation(types: &ExtractedTypes, base_url: &str) -> String {
+fn generate_client_implementation(
+    types: &ExtractedTypes,
+    base_url: &str,
+    transport: &TransportConfig,
+    generate_client: bool,
+    diags: &mut DiagnosticCollector,
+) -> String {
     let mut output = String::new();
     
     // Header
@@ -318,7 +772,7 @@ fn generate_client_implementation(types: &ExtractedTypes, base_url: &str) -> Str
         enums.sort_by(|a, b| a.name.cmp(&b.name));
         
         for py_enum in enums {
-            output.push_str(&generate_enum(py_enum));
+            output.push_str(&generate_enum(py_enum, diags));
             output.push('\n');
         }
     }
@@ -330,15 +784,28 @@ fn generate_client_implementation(types: &ExtractedTypes, base_url: &str) -> Str
         models.sort_by(|a, b| a.name.cmp(&b.name));
         
         for model in models {
-            output.push_str(&generate_interface(model));
+            output.push_str(&generate_interface(model, types, diags));
             output.push('\n');
         }
     }
     
+    // When the target opts out of the client, stop after the type definitions
+    // and leave the fetch helper and client object out entirely.
+    if !generate_client {
+        return output;
+    }
+
     // Generate the fetch helper and client
     output.push_str("// ============ PolyRPC Client ============\n\n");
-    output.push_str(&format!("const BASE_URL = '{}';\n\n", base_url));
-    
+    // Base URL resolves from POLYRPC_BASE_URL at runtime so one build works
+    // across deployments, falling back to the configured default.
+    output.push_str(&format!(
+        "const BASE_URL = (typeof process !== 'undefined' && process.env?.POLYRPC_BASE_URL) || '{}';\n",
+        base_url
+    ));
+    output.push_str(&transport_constants(transport));
+    output.push('\n');
+
     // PolyRPC Error class
     output.push_str(r#"export class PolyRPCError extends Error {
   constructor(
@@ -352,56 +819,9 @@ fn generate_client_implementation(types: &ExtractedTypes, base_url: &str) -> Str
 }
 
 "#);
-    
-    // Fetch helper with query params support
-    output.push_str(r#"async function request<TOutput>(
-  method: string,
-  path: string,
-  body?: unknown,
-  query?: Record<string, unknown>
-): Promise<TOutput> {
-  let url = `${BASE_URL}${path}`;
-  
-  // Add query parameters
-  if (query) {
-    const params = new URLSearchParams();
-    for (const [key, value] of Object.entries(query)) {
-      if (value !== undefined && value !== null) {
-        params.append(key, String(value));
-      }
-    }
-    const queryString = params.toString();
-    if (queryString) {
-      url += `?${queryString}`;
-    }
-  }
-  
-  const response = await fetch(url, {
-    method,
-    headers: {
-      'Content-Type': 'application/json',
-    },
-    body: body ? JSON.stringify(body) : undefined,
-  });
-
-  if (!response.ok) {
-    let errorData: unknown;
-    try {
-      errorData = await response.json();
-    } catch {
-      errorData = await response.text();
-    }
-    throw new PolyRPCError(
-      `Request failed: ${response.status} ${response.statusText}`,
-      response.status,
-      errorData
-    );
-  }
-
-  return response.json();
-}
 
-"#);
+    // Fetch helper with query params, default headers, timeout, and retries
+    output.push_str(&generate_request_helper(transport));
     
     // Generate the py client object with tRPC-like pattern
     output.push_str("// ============ Type-Safe Client ============\n\n");
@@ -441,7 +861,7 @@ fn generate_client_implementation(types: &ExtractedTypes, base_url: &str) -> Str
             let has_body = route.request_model.is_some();
             
             // Build input type
-            let input_type = generate_route_input_type(route);
+            let input_type = generate_route_input_type(route, types, diags);
             let needs_input = input_type != "void";
             
             // Build the path expression
@@ -449,8 +869,8 @@ fn generate_client_implementation(types: &ExtractedTypes, base_url: &str) -> Str
                 let mut path = route.path.clone();
                 for param in &route.path_params {
                     path = path.replace(
-                        &format!("{{{}}}", param),
-                        &format!("${{input.{}}}", param)
+                        &format!("{{{}}}", param.name),
+                        &format!("${{input.{}}}", param.name)
                     );
                 }
                 format!("`{}`", path)
@@ -508,7 +928,7 @@ fn generate_client_implementation(types: &ExtractedTypes, base_url: &str) -> Str
                         // Both path params and body
                         output.push_str(&format!(
                             "      mutate: (input: {} & {}) => request<{}>('{}', {}, input),\n",
-                            format!("{{ {} }}", route.path_params.iter().map(|p| format!("{}: string | number", p)).collect::<Vec<_>>().join("; ")),
+                            format!("{{ {} }}", route.path_params.iter().map(|p| format!("{}: string | number", p.name)).collect::<Vec<_>>().join("; ")),
                             route.request_model.as_ref().unwrap(),
                             response_type, method, path_expr
                         ));
@@ -550,29 +970,148 @@ fn generate_client_implementation(types: &ExtractedTypes, base_url: &str) -> Str
 }
 
 
-/// Write definitions to file
-pub fn write_definitions(path: &Path, types: &ExtractedTypes, base_url: &str) -> Result<()> {
+/// Which backend `write_definitions` emits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Type-safe TypeScript client (`.ts`).
+    TypeScript,
+    /// GraphQL schema document (`.graphql`).
+    GraphQl,
+    /// OpenAPI 3.0 specification document (`.json`).
+    OpenApi,
+}
+
+/// The TypeScript live-reload runtime appended under `watch --serve`.
+///
+/// Connects to the watcher's WebSocket on `port`, reconnecting if the dev
+/// server restarts, and exposes `onTypesUpdated` so frontend tooling can
+/// invalidate caches or trigger HMR when the generated types change.
+fn live_reload_runtime(port: u16) -> String {
+    format!(
+        r#"
+// --- PolyRPC live-reload runtime (injected by `watch --serve`) ---
+export interface TypesUpdatedEvent {{
+  type: "types-updated";
+  changed: string[];
+  models: number;
+  routes: number;
+  durationMs: number;
+}}
+
+type TypesUpdatedHandler = (event: TypesUpdatedEvent) => void;
+
+const __polyrpcHandlers: TypesUpdatedHandler[] = [];
+
+/** Register a callback fired whenever PolyRPC regenerates types. */
+export function onTypesUpdated(handler: TypesUpdatedHandler): () => void {{
+  __polyrpcHandlers.push(handler);
+  return () => {{
+    const index = __polyrpcHandlers.indexOf(handler);
+    if (index >= 0) __polyrpcHandlers.splice(index, 1);
+  }};
+}}
+
+if (typeof WebSocket !== "undefined") {{
+  const connect = () => {{
+    const socket = new WebSocket("ws://localhost:{port}");
+    socket.addEventListener("message", (event) => {{
+      try {{
+        const data = JSON.parse(event.data) as TypesUpdatedEvent;
+        if (data.type === "types-updated") {{
+          for (const handler of __polyrpcHandlers) handler(data);
+        }}
+      }} catch {{
+        // Ignore frames that are not valid JSON.
+      }}
+    }});
+    // Reconnect when the dev server restarts.
+    socket.addEventListener("close", () => setTimeout(connect, 1000));
+  }};
+  connect();
+}}
+"#
+    )
+}
+
+/// Write definitions to file (TypeScript).
+///
+/// When `live_reload` carries a port, a small WebSocket client runtime is
+/// appended to the generated `.ts` so `watch --serve` consumers can react to
+/// type changes without a full page reload.
+pub fn write_definitions(
+    path: &Path,
+    types: &ExtractedTypes,
+    base_url: &str,
+    transport: &TransportConfig,
+    generate_client: bool,
+    live_reload: Option<u16>,
+) -> Result<Vec<Diagnostic>> {
+    write_definitions_as(
+        path,
+        types,
+        base_url,
+        transport,
+        generate_client,
+        OutputFormat::TypeScript,
+        live_reload,
+    )
+}
+
+/// Write definitions for the selected output format.
+///
+/// TypeScript output is written with a `.ts` extension, GraphQL with
+/// `.graphql`, and OpenAPI with `.json`, regardless of the configured
+/// `output_file` extension. `live_reload` only applies to TypeScript output.
+pub fn write_definitions_as(
+    path: &Path,
+    types: &ExtractedTypes,
+    base_url: &str,
+    transport: &TransportConfig,
+    generate_client: bool,
+    format: OutputFormat,
+    live_reload: Option<u16>,
+) -> Result<Vec<Diagnostic>> {
     // Ensure parent directory exists
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
             .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
     }
-    
-    // Generate the complete client file (polyrpc.ts)
-    // This is the main output - a self-contained, type-safe client
-    let client_content = generate_client_implementation(types, base_url);
-    
-    // Determine output path - always use .ts extension
-    let client_path = if path.extension().map_or(false, |ext| ext == "ts") {
+
+    let mut diags = DiagnosticCollector::new();
+
+    // Resolve all references up-front so dangling references and enum/model
+    // collisions are reported before (and regardless of) codegen.
+    let _symbols = crate::resolver::resolve(types, &mut diags);
+
+    let (mut content, extension) = match format {
+        OutputFormat::TypeScript => (
+            generate_client_implementation(types, base_url, transport, generate_client, &mut diags),
+            "ts",
+        ),
+        OutputFormat::GraphQl => (crate::graphql::generate_graphql_schema(types), "graphql"),
+        OutputFormat::OpenApi => {
+            let doc = crate::openapi::to_openapi(types);
+            (serde_json::to_string_pretty(&doc).unwrap_or_default(), "json")
+        }
+    };
+
+    // Append the live-reload runtime for `watch --serve` (TypeScript only).
+    if format == OutputFormat::TypeScript {
+        if let Some(port) = live_reload {
+            content.push_str(&live_reload_runtime(port));
+        }
+    }
+
+    let out_path = if path.extension().map_or(false, |ext| ext == extension) {
         path.to_path_buf()
     } else {
-        path.with_extension("ts")
+        path.with_extension(extension)
     };
-    
-    fs::write(&client_path, &client_content)
-        .with_context(|| format!("Failed to write to {}", client_path.display()))?;
-    
-    Ok(())
+
+    fs::write(&out_path, &content)
+        .with_context(|| format!("Failed to write to {}", out_path.display()))?;
+
+    Ok(diags.into_ranked())
 }
 
 #[cfg(test)]
@@ -648,6 +1187,8 @@ mod tests {
                     optional: false,
                     default: None,
                     description: None,
+                    alias: None,
+                    constraints: crate::parser::FieldConstraints::default(),
                 },
                 ModelField {
                     name: "age".to_string(),
@@ -655,12 +1196,19 @@ mod tests {
                     optional: true,
                     default: Some("None".to_string()),
                     description: None,
+                    alias: None,
+                    constraints: crate::parser::FieldConstraints::default(),
                 },
             ],
             docstring: Some("A user model".to_string()),
+            type_params: Vec::new(),
+            bases: Vec::new(),
         };
 
-        let output = generate_interface(&model);
+        let mut types = ExtractedTypes::default();
+        types.models.insert(model.name.clone(), model.clone());
+        let mut diags = DiagnosticCollector::new();
+        let output = generate_interface(&model, &types, &mut diags);
         assert!(output.contains("export interface User"));
         assert!(output.contains("name: string;"));
         assert!(output.contains("age?: number;"));
@@ -683,7 +1231,8 @@ mod tests {
             docstring: None,
         };
 
-        let output = generate_enum(&py_enum);
+        let mut diags = DiagnosticCollector::new();
+        let output = generate_enum(&py_enum, &mut diags);
         assert!(output.contains("export enum Status"));
         assert!(output.contains("ACTIVE = \"active\""));
         assert!(output.contains("INACTIVE = \"inactive\""));
@@ -699,4 +1248,133 @@ mod tests {
         assert_eq!(convert_python_type_string("datetime"), "string");
         assert_eq!(convert_python_type_string("UUID"), "string");
     }
+
+    #[test]
+    fn test_convert_optional_and_union() {
+        assert_eq!(convert_python_type_string("Optional[User]"), "User | null");
+        assert_eq!(convert_python_type_string("Union[str, int]"), "string | number");
+        // Nested optional inside a list is parenthesized.
+        assert_eq!(
+            convert_python_type_string("List[Optional[int]]"),
+            "(number | null)[]"
+        );
+        // Comma splitting respects nested brackets.
+        assert_eq!(
+            convert_python_type_string("Union[Dict[str, int], str]"),
+            "Record<string, number> | string"
+        );
+        // Identical arms collapse.
+        assert_eq!(convert_python_type_string("Union[int, int]"), "number");
+    }
+
+    #[test]
+    fn test_convert_dict_and_mapping() {
+        assert_eq!(
+            convert_python_type_string("Dict[str, int]"),
+            "Record<string, number>"
+        );
+        // Nested value recurses, comma split respects brackets.
+        assert_eq!(
+            convert_python_type_string("Dict[str, List[User]]"),
+            "Record<string, User[]>"
+        );
+        // Non-string/number keys fall back to an index signature.
+        assert_eq!(
+            convert_python_type_string("Dict[User, int]"),
+            "{ [key: string]: number }"
+        );
+        // Aliases behave like Dict.
+        assert_eq!(
+            convert_python_type_string("Mapping[str, bool]"),
+            "Record<string, boolean>"
+        );
+        assert_eq!(
+            convert_python_type_string("DefaultDict[int, str]"),
+            "Record<number, string>"
+        );
+    }
+
+    #[test]
+    fn test_convert_tuple_variadic_and_nested() {
+        // Homogeneous variadic tuple becomes an array.
+        assert_eq!(convert_python_type_string("Tuple[int, ...]"), "number[]");
+        // Fixed-arity tuples still work.
+        assert_eq!(
+            convert_python_type_string("Tuple[str, int]"),
+            "[string, number]"
+        );
+        // Deeply nested tuples recurse through the bracket-aware splitter.
+        assert_eq!(
+            convert_python_type_string("Tuple[Tuple[str, int], List[User]]"),
+            "[[string, number], User[]]"
+        );
+    }
+
+    #[test]
+    fn test_convert_to_rust() {
+        assert_eq!(convert_python_type_to_rust("str"), "String");
+        assert_eq!(convert_python_type_to_rust("int"), "i64");
+        assert_eq!(convert_python_type_to_rust("List[User]"), "Vec<User>");
+        assert_eq!(convert_python_type_to_rust("Set[str]"), "HashSet<String>");
+        assert_eq!(
+            convert_python_type_to_rust("FrozenSet[int]"),
+            "BTreeSet<i64>"
+        );
+        assert_eq!(
+            convert_python_type_to_rust("Dict[str, int]"),
+            "HashMap<String, i64>"
+        );
+        assert_eq!(convert_python_type_to_rust("Optional[User]"), "Option<User>");
+        assert_eq!(convert_python_type_to_rust("Tuple[str, int]"), "(String, i64)");
+        assert_eq!(convert_python_type_to_rust("UUID"), "uuid::Uuid");
+        assert_eq!(
+            convert_python_type_to_rust("datetime"),
+            "chrono::DateTime<Utc>"
+        );
+        assert_eq!(
+            convert_python_type_to_rust("Decimal"),
+            "rust_decimal::Decimal"
+        );
+        assert_eq!(TargetLang::Rust.convert("List[int]"), "Vec<i64>");
+        assert_eq!(TargetLang::TypeScript.convert("List[int]"), "number[]");
+    }
+
+    #[test]
+    fn test_convert_literal() {
+        assert_eq!(
+            convert_python_type_string("Literal[\"a\", \"b\", 3]"),
+            "\"a\" | \"b\" | 3"
+        );
+        assert_eq!(
+            convert_python_type_string("Literal[True, False]"),
+            "true | false"
+        );
+        // A literal arm inside a list composes as an array.
+        assert_eq!(
+            convert_python_type_string("List[Literal[\"x\", \"y\"]]"),
+            "(\"x\" | \"y\")[]"
+        );
+    }
+
+    #[test]
+    fn test_literal_pytype_path() {
+        // The PyType path (used by every model field and query param) must keep
+        // strings quoted and emit numeric/boolean members bare, just like the
+        // string path above.
+        assert_eq!(
+            py_type_to_ts(&PyType::Literal(vec![
+                "\"a\"".to_string(),
+                "\"b\"".to_string(),
+                "3".to_string(),
+            ])),
+            "\"a\" | \"b\" | 3"
+        );
+        assert_eq!(
+            py_type_to_ts(&PyType::Literal(vec![
+                "True".to_string(),
+                "False".to_string(),
+            ])),
+            "true | false"
+        );
+    }
 }
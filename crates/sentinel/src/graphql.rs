@@ -0,0 +1,212 @@
+//! GraphQL SDL output target.
+//!
+//! A second code-generation backend that emits a GraphQL schema (SDL) from the
+//! same [`ExtractedTypes`] the TypeScript client is built from. Pydantic models
+//! become `type`/`input` object definitions, enums become `enum`s, and each
+//! route becomes a field under the root `Query` (GET) or `Mutation` (the other
+//! methods) with its params mapped into field arguments.
+
+use crate::parser::{ApiRoute, ExtractedTypes, PyType, PydanticModel};
+use std::collections::BTreeSet;
+
+/// Suffix distinguishing an input object from its output counterpart so request
+/// bodies and responses never collide.
+const INPUT_SUFFIX: &str = "Input";
+
+/// Generate a GraphQL schema document (SDL) from the extracted types.
+pub fn generate_graphql_schema(types: &ExtractedTypes) -> String {
+    let mut out = String::new();
+
+    out.push_str("# Auto-generated by PolyRPC - DO NOT EDIT\n");
+    out.push_str("# GraphQL schema derived from Python types\n\n");
+
+    // Custom scalars used by the mappings below, declared once up front.
+    out.push_str("scalar DateTime\n");
+    out.push_str("scalar Date\n");
+    out.push_str("scalar Time\n");
+    out.push_str("scalar UUID\n");
+    out.push_str("scalar Decimal\n");
+    out.push_str("scalar JSON\n\n");
+
+    // Enums.
+    let mut enums: Vec<_> = types.enums.values().collect();
+    enums.sort_by(|a, b| a.name.cmp(&b.name));
+    for py_enum in enums {
+        out.push_str(&format!("enum {} {{\n", py_enum.name));
+        for variant in &py_enum.variants {
+            out.push_str(&format!("  {}\n", variant.name));
+        }
+        out.push_str("}\n\n");
+    }
+
+    // Output object types.
+    let mut models: Vec<_> = types.models.values().collect();
+    models.sort_by(|a, b| a.name.cmp(&b.name));
+    for model in &models {
+        out.push_str(&object_definition("type", &model.name, model));
+    }
+
+    // Input object types, emitted only for models used as request bodies.
+    let body_models: BTreeSet<&str> = types
+        .routes
+        .iter()
+        .filter_map(|r| r.request_model.as_deref())
+        .collect();
+    for model in &models {
+        if body_models.contains(model.name.as_str()) {
+            let input_name = format!("{}{}", model.name, INPUT_SUFFIX);
+            out.push_str(&object_definition("input", &input_name, model));
+        }
+    }
+
+    // Root Query / Mutation fields from routes.
+    let (queries, mutations): (Vec<_>, Vec<_>) = types
+        .routes
+        .iter()
+        .partition(|r| r.method == "GET");
+
+    out.push_str(&root_type("Query", &queries));
+    out.push_str(&root_type("Mutation", &mutations));
+
+    out
+}
+
+/// Emit an object (`type` or `input`) definition for a model.
+fn object_definition(keyword: &str, name: &str, model: &PydanticModel) -> String {
+    let mut out = String::new();
+    if let Some(doc) = &model.docstring {
+        out.push_str(&format!("\"\"\"{}\"\"\"\n", doc.trim()));
+    }
+    out.push_str(&format!("{} {} {{\n", keyword, name));
+    for field in &model.fields {
+        let ty = py_type_to_graphql(&field.py_type, field.optional);
+        out.push_str(&format!("  {}: {}\n", field.name, ty));
+    }
+    out.push_str("}\n\n");
+    out
+}
+
+/// Emit a root `Query`/`Mutation` type from a set of routes, if non-empty.
+fn root_type(name: &str, routes: &[&ApiRoute]) -> String {
+    if routes.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("type {} {{\n", name);
+    for route in routes {
+        let args = field_arguments(route);
+        let response = route
+            .response_model
+            .as_deref()
+            .map(graphql_named_type)
+            .unwrap_or_else(|| "Boolean".to_string());
+        if args.is_empty() {
+            out.push_str(&format!("  {}: {}\n", route.function_name, response));
+        } else {
+            out.push_str(&format!("  {}({}): {}\n", route.function_name, args, response));
+        }
+    }
+    out.push_str("}\n\n");
+    out
+}
+
+/// Build the GraphQL argument list for a route: path params, query params, and
+/// the request body (as the matching input object).
+fn field_arguments(route: &ApiRoute) -> String {
+    let mut args = Vec::new();
+
+    for param in &route.path_params {
+        args.push(format!(
+            "{}: {}",
+            param.name,
+            py_type_to_graphql(&param.py_type, false)
+        ));
+    }
+    for param in &route.query_params {
+        args.push(format!("{}: {}", param.name, py_type_to_graphql(&param.py_type, param.optional)));
+    }
+    if let Some(model) = &route.request_model {
+        args.push(format!("input: {}{}!", model, INPUT_SUFFIX));
+    }
+
+    args.join(", ")
+}
+
+/// Map a parsed type to a GraphQL type, honoring nullability.
+///
+/// GraphQL types are non-null by default (`Type!`); an `optional` field or an
+/// `Optional[..]` wrapper drops the trailing `!`.
+pub fn py_type_to_graphql(py_type: &PyType, optional: bool) -> String {
+    let base = graphql_non_null(py_type);
+    if optional || matches!(py_type, PyType::Optional(_)) {
+        base.strip_suffix('!').unwrap_or(&base).to_string()
+    } else {
+        base
+    }
+}
+
+/// Map a type to its non-null GraphQL form (with a trailing `!`).
+fn graphql_non_null(py_type: &PyType) -> String {
+    match py_type {
+        PyType::String | PyType::Bytes => "String!".to_string(),
+        PyType::Int | PyType::TimeDelta => "Int!".to_string(),
+        PyType::Float => "Float!".to_string(),
+        PyType::Bool => "Boolean!".to_string(),
+        PyType::DateTime => "DateTime!".to_string(),
+        PyType::Date => "Date!".to_string(),
+        PyType::Time => "Time!".to_string(),
+        PyType::UUID => "UUID!".to_string(),
+        PyType::Decimal => "Decimal!".to_string(),
+        PyType::List(inner) | PyType::Set(inner) | PyType::FrozenSet(inner) => {
+            format!("[{}]!", graphql_non_null(inner))
+        }
+        PyType::Optional(inner) => {
+            let inner = graphql_non_null(inner);
+            // Re-wrap so the outer caller can strip the `!` once.
+            format!("{}!", inner.strip_suffix('!').unwrap_or(&inner))
+        }
+        PyType::Reference(name) | PyType::Generic(name) => format!("{}!", name),
+        PyType::GenericType(base, _) => format!("{}!", base),
+        // Dicts, tuples, unions, literals and anything else degrade to JSON.
+        _ => "JSON!".to_string(),
+    }
+}
+
+/// Map a raw response/request type string to a GraphQL named type.
+fn graphql_named_type(raw: &str) -> String {
+    let raw = raw.trim();
+    // Unwrap a List[...] wrapper into a GraphQL list of the inner type.
+    for prefix in ["List[", "list["] {
+        if let Some(rest) = raw.strip_prefix(prefix) {
+            if let Some(inner) = rest.strip_suffix(']') {
+                return format!("[{}!]!", graphql_named_type(inner));
+            }
+        }
+    }
+    match raw {
+        "str" => "String".to_string(),
+        "int" => "Int".to_string(),
+        "float" => "Float".to_string(),
+        "bool" => "Boolean".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_py_type_to_graphql_nullability() {
+        assert_eq!(py_type_to_graphql(&PyType::String, false), "String!");
+        assert_eq!(py_type_to_graphql(&PyType::String, true), "String");
+        assert_eq!(
+            py_type_to_graphql(&PyType::List(Box::new(PyType::Int)), false),
+            "[Int!]!"
+        );
+        assert_eq!(
+            py_type_to_graphql(&PyType::Optional(Box::new(PyType::String)), false),
+            "String"
+        );
+    }
+}
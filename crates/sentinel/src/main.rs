@@ -3,9 +3,14 @@
 //! This is the core binary that watches Python files and generates
 //! TypeScript definitions in real-time.
 
+mod check;
 mod config;
+mod diagnostics;
 mod parser;
+mod resolver;
 mod generator;
+mod graphql;
+mod openapi;
 mod watcher;
 
 use anyhow::Result;
@@ -27,14 +32,43 @@ enum Commands {
     Init,
     /// Watch Python files and generate TypeScript types
     Watch {
-        /// Path to config file
-        #[arg(short, long, default_value = "polyrpc.toml")]
-        config: PathBuf,
+        /// Path to config file (auto-discovered by walking parents if omitted)
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Environment profile to apply (development/production)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Start a live-reload WebSocket server on this port and inject a
+        /// client runtime into the generated TypeScript.
+        #[arg(long)]
+        serve: Option<u16>,
+        /// Clear the terminal before each regeneration (the default).
+        #[arg(long, overrides_with = "no_clear_screen")]
+        clear_screen: bool,
+        /// Leave previous output on screen between regenerations.
+        #[arg(long, overrides_with = "clear_screen")]
+        no_clear_screen: bool,
     },
     /// Generate types once (no watch)
     Generate {
-        #[arg(short, long, default_value = "polyrpc.toml")]
-        config: PathBuf,
+        /// Path to config file (auto-discovered by walking parents if omitted)
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Environment profile to apply (development/production)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Output target: typescript (default), graphql, or openapi
+        #[arg(short, long, default_value = "typescript")]
+        target: String,
+    },
+    /// Validate the config without generating anything
+    Check {
+        /// Path to config file (auto-discovered by walking parents if omitted)
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Environment profile to apply (development/production)
+        #[arg(short, long)]
+        environment: Option<String>,
     },
 }
 
@@ -54,60 +88,123 @@ async fn main() -> Result<()> {
             println!("{} Created polyrpc.toml", "✓".green());
             println!("{} Run `polyrpc watch` to start", "→".blue());
         }
-        Commands::Watch { config } => {
-            let cfg = config::load_config(&config)?;
-            
-            // Resolve paths relative to config file location
-            let config_dir = config.parent().unwrap_or(std::path::Path::new("."));
-            let source_dir = config_dir.join(&cfg.python.source_dir);
-            let output_file = config_dir.join(&cfg.typescript.output_file);
-            
+        Commands::Watch { config, environment, serve, no_clear_screen, .. } => {
+            let (cfg, path) = resolve_config(config, environment.as_deref())?;
+            report_config(path.as_deref());
+
             println!(
                 "{} Watching {} → {}",
                 "👁".bright_yellow(),
-                source_dir.display().to_string().cyan(),
-                output_file.display().to_string().green()
+                cfg.python.primary_dir().display().to_string().cyan(),
+                cfg.typescript.output_file.display().to_string().green()
             );
-            
-            // Create a new config with resolved paths
-            let resolved_cfg = config::Config {
-                python: config::PythonConfig {
-                    source_dir,
-                    include: cfg.python.include,
-                    exclude: cfg.python.exclude,
-                },
-                typescript: config::TypeScriptConfig {
-                    output_file,
-                    generate_client: cfg.typescript.generate_client,
-                },
-                api: cfg.api,
+
+            // Clearing is on by default; `--no-clear-screen` disables it.
+            watcher::watch(cfg, serve, !no_clear_screen).await?;
+        }
+        Commands::Generate { config, environment, target } => {
+            let (cfg, path) = resolve_config(config, environment.as_deref())?;
+            report_config(path.as_deref());
+
+            let format = match target.to_ascii_lowercase().as_str() {
+                "typescript" | "ts" => generator::OutputFormat::TypeScript,
+                "graphql" | "gql" => generator::OutputFormat::GraphQl,
+                "openapi" | "oas" => generator::OutputFormat::OpenApi,
+                other => anyhow::bail!(
+                    "unknown target '{other}' (expected typescript, graphql, or openapi)"
+                ),
             };
-            
-            watcher::watch(resolved_cfg).await?;
+
+            // Validate before generating; abort on any hard error.
+            if let Some(p) = path.as_deref() {
+                let raw = std::fs::read_to_string(p).unwrap_or_default();
+                if !check::validate(&cfg, &raw).is_empty() {
+                    check::report(&cfg, &raw, p);
+                    anyhow::bail!("config validation failed");
+                }
+            }
+
+            for target in cfg.resolved_targets() {
+                // Parse per target so a monorepo target's own include/exclude
+                // globs actually scope what it sees.
+                let types = parser::parse_sources(
+                    &cfg.python.source_dirs,
+                    &target.include,
+                    &target.exclude,
+                )?;
+                let diags = generator::write_definitions_as(
+                    &target.output_file,
+                    &types,
+                    &target.base_url,
+                    &target.transport,
+                    target.generate_client,
+                    format,
+                    None,
+                )?;
+                println!(
+                    "{} Generated {} models, {} enums, {} routes → {}",
+                    "✓".green(),
+                    types.models.len().to_string().bright_yellow(),
+                    types.enums.len().to_string().bright_yellow(),
+                    types.routes.len().to_string().bright_yellow(),
+                    target.output_file.display().to_string().green()
+                );
+                report_diagnostics(&diags);
+            }
         }
-        Commands::Generate { config } => {
-            let cfg = config::load_config(&config)?;
-            
-            // Resolve paths relative to config file location
-            let config_dir = config.parent().unwrap_or(std::path::Path::new("."));
-            let source_dir = config_dir.join(&cfg.python.source_dir);
-            let output_file = config_dir.join(&cfg.typescript.output_file);
-            
-            let types = parser::parse_directory(&source_dir)?;
-            generator::write_definitions(
-                &output_file,
-                &types,
-                &cfg.api.base_url,
-            )?;
-            println!(
-                "{} Generated {} models, {} enums, {} routes",
-                "✓".green(),
-                types.models.len().to_string().bright_yellow(),
-                types.enums.len().to_string().bright_yellow(),
-                types.routes.len().to_string().bright_yellow()
-            );
+        Commands::Check { config, environment } => {
+            let (cfg, path) = resolve_config(config, environment.as_deref())?;
+            match path.as_deref() {
+                Some(p) => {
+                    let raw = std::fs::read_to_string(p).unwrap_or_default();
+                    if !check::report(&cfg, &raw, p) {
+                        anyhow::bail!("config validation failed");
+                    }
+                }
+                None => println!("{} No config found, nothing to check", "→".blue()),
+            }
         }
     }
 
     Ok(())
 }
+
+/// Load the config either from an explicit `-c` path or by auto-discovery,
+/// resolving its relative paths against the config file's directory.
+fn resolve_config(
+    config: Option<PathBuf>,
+    environment: Option<&str>,
+) -> Result<(config::Config, Option<PathBuf>)> {
+    let (mut cfg, path) = match config {
+        Some(path) => (config::load_config(&path, environment)?, Some(path)),
+        None => config::Config::load_or_default(environment)?,
+    };
+
+    let config_dir = path
+        .as_deref()
+        .and_then(|p| p.parent())
+        .unwrap_or_else(|| std::path::Path::new("."))
+        .to_path_buf();
+    cfg.resolve_relative_to(&config_dir);
+
+    Ok((cfg, path))
+}
+
+/// Print generation diagnostics (errors first), if any.
+fn report_diagnostics(diags: &[diagnostics::Diagnostic]) {
+    if diags.is_empty() {
+        return;
+    }
+    println!("{} {} diagnostic(s):", "!".yellow(), diags.len());
+    for diag in diags {
+        println!("  {}", diag);
+    }
+}
+
+/// Print which config file was used, or note that defaults were applied.
+fn report_config(path: Option<&std::path::Path>) {
+    match path {
+        Some(p) => println!("{} Using config {}", "→".blue(), p.display().to_string().cyan()),
+        None => println!("{} No config found, using defaults", "→".blue()),
+    }
+}
@@ -0,0 +1,283 @@
+//! OpenAPI 3.0 output target.
+//!
+//! A third code-generation backend that serializes the same [`ExtractedTypes`]
+//! the TypeScript client and GraphQL schema are built from into an OpenAPI 3.0
+//! document. Pydantic models become `components/schemas/{Name}` entries and each
+//! route becomes an operation under `paths[path][method]`, so downstream tooling
+//! (Swagger UI, client generators) can consume the spec directly.
+
+use crate::parser::{ApiRoute, ExtractedTypes, ModelField, PyType, PydanticModel};
+use serde_json::{json, Map, Value};
+
+/// Build an OpenAPI 3.0 document from the extracted models and routes.
+pub fn to_openapi(extracted: &ExtractedTypes) -> Value {
+    let mut paths = Map::new();
+    for route in &extracted.routes {
+        let entry = paths
+            .entry(route.path.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(methods) = entry {
+            methods.insert(route.method.to_lowercase(), operation(route));
+        }
+    }
+
+    let mut schemas = Map::new();
+    for model in extracted.models.values() {
+        schemas.insert(model.name.clone(), schema_object(model));
+    }
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "PolyRPC API",
+            "version": "1.0.0",
+        },
+        "paths": Value::Object(paths),
+        "components": {
+            "schemas": Value::Object(schemas),
+        },
+    })
+}
+
+/// Serialize a single route into an OpenAPI operation object.
+fn operation(route: &ApiRoute) -> Value {
+    let mut op = Map::new();
+    op.insert("operationId".to_string(), json!(route.function_name));
+    if !route.tags.is_empty() {
+        op.insert("tags".to_string(), json!(route.tags));
+    }
+    if route.deprecated {
+        op.insert("deprecated".to_string(), json!(true));
+    }
+
+    let mut parameters = Vec::new();
+    for param in &route.path_params {
+        parameters.push(json!({
+            "name": param.name,
+            "in": "path",
+            "required": true,
+            "schema": type_schema(&param.py_type),
+        }));
+    }
+    for param in &route.query_params {
+        let mut spec = json!({
+            "name": param.name,
+            "in": "query",
+            "required": !is_optional(param),
+            "schema": type_schema(&param.py_type),
+        });
+        // Repeated (list-valued) query keys serialize as `?k=1&k=2`.
+        if route.multi_value_query.contains(&param.name) {
+            spec["style"] = json!("form");
+            spec["explode"] = json!(true);
+        }
+        parameters.push(spec);
+    }
+    if !parameters.is_empty() {
+        op.insert("parameters".to_string(), Value::Array(parameters));
+    }
+
+    if let Some(model) = &route.request_model {
+        op.insert(
+            "requestBody".to_string(),
+            json!({
+                "required": true,
+                "content": {
+                    "application/json": { "schema": model_schema(model) },
+                },
+            }),
+        );
+    }
+
+    let mut ok = Map::new();
+    ok.insert("description".to_string(), json!("Successful response"));
+    if let Some(model) = &route.response_model {
+        ok.insert(
+            "content".to_string(),
+            json!({ "application/json": { "schema": model_schema(model) } }),
+        );
+    }
+    let mut responses = Map::new();
+    responses.insert(success_status(route), Value::Object(ok));
+    op.insert("responses".to_string(), Value::Object(responses));
+
+    Value::Object(op)
+}
+
+/// The response status key for a route: the three-digit code embedded in the
+/// decorator's `status_code=` (handling both `201` and `status.HTTP_201_…`),
+/// defaulting to `200`.
+fn success_status(route: &ApiRoute) -> String {
+    route
+        .status_code
+        .as_deref()
+        .and_then(extract_status_digits)
+        .unwrap_or_else(|| "200".to_string())
+}
+
+/// Pull the first maximal run of exactly three ASCII digits from `raw`.
+fn extract_status_digits(raw: &str) -> Option<String> {
+    let bytes = raw.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_digit() {
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i - start == 3 {
+                return Some(raw[start..i].to_string());
+            }
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+/// Serialize a model into an OpenAPI schema object, tracking required fields.
+fn schema_object(model: &PydanticModel) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in &model.fields {
+        properties.insert(field.name.clone(), type_schema(&field.py_type));
+        if !is_optional(field) {
+            required.push(Value::String(field.name.clone()));
+        }
+    }
+
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("object"));
+    schema.insert("properties".to_string(), Value::Object(properties));
+    if let Some(doc) = &model.docstring {
+        schema.insert("description".to_string(), json!(doc.trim()));
+    }
+    if !required.is_empty() {
+        schema.insert("required".to_string(), Value::Array(required));
+    }
+    Value::Object(schema)
+}
+
+/// Whether a field is nullable (declared `Optional[..]` or with a default).
+fn is_optional(field: &ModelField) -> bool {
+    field.optional || matches!(field.py_type, PyType::Optional(_))
+}
+
+/// Map a parsed type to an OpenAPI schema fragment.
+fn type_schema(py_type: &PyType) -> Value {
+    match py_type {
+        PyType::String | PyType::Bytes => json!({ "type": "string" }),
+        PyType::Int | PyType::TimeDelta => json!({ "type": "integer" }),
+        PyType::Float => json!({ "type": "number" }),
+        PyType::Bool => json!({ "type": "boolean" }),
+        PyType::DateTime => json!({ "type": "string", "format": "date-time" }),
+        PyType::Date => json!({ "type": "string", "format": "date" }),
+        PyType::Time => json!({ "type": "string", "format": "time" }),
+        PyType::UUID => json!({ "type": "string", "format": "uuid" }),
+        PyType::Decimal => json!({ "type": "string", "format": "decimal" }),
+        PyType::List(inner) | PyType::Set(inner) | PyType::FrozenSet(inner) => {
+            json!({ "type": "array", "items": type_schema(inner) })
+        }
+        PyType::Dict(_, value) => {
+            json!({ "type": "object", "additionalProperties": type_schema(value) })
+        }
+        PyType::Tuple(_) => json!({ "type": "array" }),
+        PyType::Optional(inner) => type_schema(inner),
+        PyType::Union(members) => {
+            json!({ "oneOf": members.iter().map(type_schema).collect::<Vec<_>>() })
+        }
+        PyType::Literal(values) => {
+            json!({ "enum": values.iter().map(|v| literal_value(v)).collect::<Vec<_>>() })
+        }
+        PyType::Reference(name) | PyType::Generic(name) => ref_schema(name),
+        PyType::GenericType(base, _) => ref_schema(base),
+        // Any, None and Unknown map to the permissive empty schema.
+        _ => json!({}),
+    }
+}
+
+/// Build a schema from a raw request/response annotation string. The stored
+/// text may be a container (`List[User]`, `Optional[User]`, `dict[str, User]`)
+/// rather than a bare model name, so parse it into a [`PyType`] and route it
+/// through [`type_schema`]; a plain name still resolves to a `$ref`.
+fn model_schema(annotation: &str) -> Value {
+    type_schema(&crate::parser::parse_type_annotation(annotation))
+}
+
+/// A `$ref` pointing at a named schema under `components/schemas`.
+fn ref_schema(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{name}") })
+}
+
+/// Interpret a raw `Literal[..]` member as a JSON value: unquote strings, and
+/// recognize numeric and boolean members.
+fn literal_value(raw: &str) -> Value {
+    let trimmed = raw.trim();
+    if let Some(inner) = trimmed
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| trimmed.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+    {
+        return Value::String(inner.to_string());
+    }
+    match trimmed {
+        "True" => return Value::Bool(true),
+        "False" => return Value::Bool(false),
+        _ => {}
+    }
+    if let Ok(n) = trimmed.parse::<i64>() {
+        return json!(n);
+    }
+    if let Ok(n) = trimmed.parse::<f64>() {
+        return json!(n);
+    }
+    Value::String(trimmed.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_source;
+
+    #[test]
+    fn test_to_openapi_models_and_routes() {
+        let source = r#"
+from pydantic import BaseModel
+from typing import Optional
+from fastapi import FastAPI
+
+class User(BaseModel):
+    id: int
+    name: str
+    nickname: Optional[str] = None
+
+app = FastAPI()
+
+@app.get("/users/{id}")
+def get_user(id: int) -> User:
+    ...
+"#;
+        let types = parse_source(source).unwrap();
+        let doc = to_openapi(&types);
+
+        assert_eq!(doc["openapi"], "3.0.3");
+
+        let user = &doc["components"]["schemas"]["User"];
+        assert_eq!(user["properties"]["id"]["type"], "integer");
+        let required = user["required"].as_array().unwrap();
+        assert!(required.iter().any(|v| v == "id"));
+        assert!(required.iter().any(|v| v == "name"));
+        // Optional field is dropped from `required`.
+        assert!(!required.iter().any(|v| v == "nickname"));
+
+        let op = &doc["paths"]["/users/{id}"]["get"];
+        let params = op["parameters"].as_array().unwrap();
+        let path_param = params.iter().find(|p| p["in"] == "path").unwrap();
+        assert_eq!(path_param["name"], "id");
+        assert_eq!(path_param["required"], true);
+        assert_eq!(
+            op["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/User"
+        );
+    }
+}
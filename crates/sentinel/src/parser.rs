@@ -3,11 +3,13 @@
 //! This module extracts Pydantic models and FastAPI routes from Python source files
 //! without needing a Python runtime.
 
+use crate::config::SourceDir;
 use anyhow::{Context, Result};
-use std::collections::HashMap;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
-use tree_sitter::{Node, Parser, Query, QueryCursor};
+use std::path::{Path, PathBuf};
+use tree_sitter::{InputEdit, Node, Parser, Point, Query, QueryCursor, Tree};
 use walkdir::WalkDir;
 
 /// Represents a parsed Python type
@@ -70,6 +72,33 @@ pub struct EnumVariant {
     pub value: String,
 }
 
+/// Validation constraints carried by a `Field(...)` declaration.
+#[derive(Debug, Clone, Default)]
+pub struct FieldConstraints {
+    pub ge: Option<f64>,
+    pub gt: Option<f64>,
+    pub le: Option<f64>,
+    pub lt: Option<f64>,
+    pub min_length: Option<i64>,
+    pub max_length: Option<i64>,
+    /// Regular-expression pattern from `pattern=` (or the legacy `regex=`).
+    pub pattern: Option<String>,
+}
+
+impl FieldConstraints {
+    /// Whether no constraint was supplied.
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.ge.is_none()
+            && self.gt.is_none()
+            && self.le.is_none()
+            && self.lt.is_none()
+            && self.min_length.is_none()
+            && self.max_length.is_none()
+            && self.pattern.is_none()
+    }
+}
+
 /// A field in a Pydantic model
 #[derive(Debug, Clone)]
 pub struct ModelField {
@@ -79,6 +108,12 @@ pub struct ModelField {
     #[allow(dead_code)]
     pub default: Option<String>,
     pub description: Option<String>,
+    /// Serialization alias from `Field(alias="...")`, when present.
+    #[allow(dead_code)]
+    pub alias: Option<String>,
+    /// Validation constraints parsed from a `Field(...)` declaration.
+    #[allow(dead_code)]
+    pub constraints: FieldConstraints,
 }
 
 /// A Pydantic model definition
@@ -87,6 +122,12 @@ pub struct PydanticModel {
     pub name: String,
     pub fields: Vec<ModelField>,
     pub docstring: Option<String>,
+    /// Declared type parameters from a `Generic[T, ...]` base, in order.
+    pub type_params: Vec<String>,
+    /// Base class identifiers in declaration order, used to merge inherited
+    /// fields. Bases that are not themselves extracted models (e.g.
+    /// `BaseModel`) are left as opaque external references.
+    pub bases: Vec<String>,
 }
 
 /// A FastAPI route definition
@@ -95,11 +136,50 @@ pub struct ApiRoute {
     pub method: String,        // GET, POST, PUT, DELETE, PATCH
     pub path: String,          // /users/{id}
     pub function_name: String,
+    /// The request body parameter's model name, if the handler takes one.
     pub request_model: Option<String>,
     pub response_model: Option<String>,
     #[allow(dead_code)]
     pub query_params: Vec<ModelField>,
-    pub path_params: Vec<String>,
+    pub path_params: Vec<PathParam>,
+    /// Parameters declared with `Header(...)`, classified out of the query set.
+    #[allow(dead_code)]
+    pub header_params: Vec<ModelField>,
+    /// Names of query params that accept repeated values (`?id=1&id=2`): those
+    /// typed `List[..]` or a `Union` containing a list. Their element type is in
+    /// the matching [`ApiRoute::query_params`] entry.
+    #[allow(dead_code)]
+    pub multi_value_query: Vec<String>,
+    /// Callables injected via `Depends(...)`/`Security(...)`, kept out of the
+    /// client call signature since the framework supplies them.
+    #[allow(dead_code)]
+    pub dependencies: Vec<String>,
+    /// The subset of `dependencies` that look like security schemes (OAuth2,
+    /// API key, HTTP bearer/basic), for later `securitySchemes` emission.
+    #[allow(dead_code)]
+    pub security_schemes: Vec<String>,
+    /// Success status code from the decorator's `status_code=`, if given.
+    #[allow(dead_code)]
+    pub status_code: Option<String>,
+    /// Grouping tags from the decorator's `tags=[...]`.
+    #[allow(dead_code)]
+    pub tags: Vec<String>,
+    /// Whether the decorator marked the route `deprecated=True`.
+    #[allow(dead_code)]
+    pub deprecated: bool,
+}
+
+/// A path parameter with the type it resolves to.
+///
+/// The type is taken from the handler's matching typed argument when present,
+/// otherwise from the path converter suffix (`{id:int}`), falling back to a
+/// string. `wildcard` records a `{rest:path}` catch-all that may span `/`.
+#[derive(Debug, Clone)]
+pub struct PathParam {
+    pub name: String,
+    pub py_type: PyType,
+    #[allow(dead_code)]
+    pub wildcard: bool,
 }
 
 /// All extracted types from Python source
@@ -108,31 +188,501 @@ pub struct ExtractedTypes {
     pub models: HashMap<String, PydanticModel>,
     pub enums: HashMap<String, PyEnum>,
     pub routes: Vec<ApiRoute>,
+    /// Module-level `T = TypeVar('T', bound=...)` declarations, mapping the
+    /// variable name to its declared bound (if any). Used to fall back to a
+    /// concrete type when a generic model is referenced unparameterized.
+    pub type_vars: HashMap<String, Option<PyType>>,
+    /// Per-file import aliases: a locally-visible name mapped to the canonical
+    /// symbol it refers to (`from .models import User as U` -> `U` → `User`).
+    /// Used to rewrite references to their imported target before merging.
+    pub imports: HashMap<String, String>,
+}
+
+/// Decides which files under the source tree are eligible for parsing.
+///
+/// Compiles `python.include`/`python.exclude` into [`GlobSet`]s and, when a
+/// `.gitignore` sits at the source root, an additional ignore set, so both the
+/// one-shot `generate` and the long-running `watch` feed the generator exactly
+/// the same set of files. Globs are matched against the path relative to the
+/// source root, so the familiar `**/*.py` / `**/test_*.py` patterns work the
+/// same whether the walker hands over absolute or relative paths.
+pub struct SourceFilter {
+    root: PathBuf,
+    include: GlobSet,
+    exclude: GlobSet,
+    gitignore: Option<GlobSet>,
+}
+
+impl SourceFilter {
+    /// Build a filter rooted at `root` from the config's glob lists, reading a
+    /// `root/.gitignore` if one exists.
+    pub fn new(root: &Path, include: &[String], exclude: &[String]) -> Result<Self> {
+        let gitignore = match fs::read_to_string(root.join(".gitignore")) {
+            Ok(content) => gitignore_globset(&content)?,
+            Err(_) => None,
+        };
+        Ok(SourceFilter {
+            root: root.to_path_buf(),
+            include: build_globset(include)?,
+            exclude: build_globset(exclude)?,
+            gitignore,
+        })
+    }
+
+    /// Whether `path` should be parsed: included, not excluded, not ignored.
+    pub fn accepts(&self, path: &Path) -> bool {
+        let rel = path.strip_prefix(&self.root).unwrap_or(path);
+        if !self.include.is_match(rel) || self.exclude.is_match(rel) {
+            return false;
+        }
+        match &self.gitignore {
+            Some(gi) => !gi.is_match(rel),
+            None => true,
+        }
+    }
 }
 
-/// Parse all Python files in a directory
-pub fn parse_directory(dir: &Path) -> Result<ExtractedTypes> {
+/// Compile a list of glob patterns into a [`GlobSet`].
+fn build_globset(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(
+            Glob::new(pattern).with_context(|| format!("invalid glob pattern '{pattern}'"))?,
+        );
+    }
+    Ok(builder.build()?)
+}
+
+/// Compile a `.gitignore` into a match-any [`GlobSet`].
+///
+/// This covers the common cases — comments, blank lines, directory entries and
+/// path fragments — by expanding each entry into the anchored and unanchored
+/// variants tree-sitter consumers actually hit; full git semantics (negation,
+/// re-inclusion) are intentionally out of scope. Returns `None` when nothing
+/// parseable remains so callers can skip the check entirely.
+fn gitignore_globset(content: &str) -> Result<Option<GlobSet>> {
+    let mut builder = GlobSetBuilder::new();
+    let mut any = false;
+    for line in content.lines() {
+        let line = line.trim();
+        // Skip comments, blanks, and negation rules we don't model.
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+        let pat = line.trim_start_matches('/').trim_end_matches('/');
+        if pat.is_empty() {
+            continue;
+        }
+        for variant in [
+            pat.to_string(),
+            format!("**/{pat}"),
+            format!("{pat}/**"),
+            format!("**/{pat}/**"),
+        ] {
+            if let Ok(glob) = Glob::new(&variant) {
+                builder.add(glob);
+                any = true;
+            }
+        }
+    }
+    if any {
+        Ok(Some(builder.build()?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Parse all Python files in a directory, honoring the include/exclude/ignore
+/// filter so `generate` and `watch` see an identical file set.
+pub fn parse_directory(dir: &Path, filter: &SourceFilter) -> Result<ExtractedTypes> {
     let mut extracted = ExtractedTypes::default();
-    
+    let mut defined_in: HashMap<String, PathBuf> = HashMap::new();
+
+    merge_directory(dir, filter, &mut extracted, &mut defined_in);
+
+    resolve_model_inheritance(&mut extracted.models);
+    monomorphize_generics(&mut extracted);
+
+    Ok(extracted)
+}
+
+/// Parse and merge several source roots into one [`ExtractedTypes`].
+///
+/// Each root gets its own [`SourceFilter`] (so relative globs and a per-root
+/// `.gitignore` resolve correctly), and duplicate type names are tracked across
+/// roots, not just within one. A required root that is missing aborts; an
+/// optional one is skipped with a warning.
+pub fn parse_sources(
+    sources: &[SourceDir],
+    include: &[String],
+    exclude: &[String],
+) -> Result<ExtractedTypes> {
+    let mut extracted = ExtractedTypes::default();
+    // Track where each model/enum name was first defined so a second definition
+    // in another module (or another root) is reported with a warning; the
+    // later definition still replaces the earlier one.
+    let mut defined_in: HashMap<String, PathBuf> = HashMap::new();
+
+    for source in sources {
+        let dir = source.path();
+        if !dir.is_dir() {
+            if source.required() {
+                anyhow::bail!("required source directory does not exist: {}", dir.display());
+            }
+            eprintln!(
+                "Warning: skipping missing optional source directory {}",
+                dir.display()
+            );
+            continue;
+        }
+        let filter = SourceFilter::new(dir, include, exclude)?;
+        merge_directory(dir, &filter, &mut extracted, &mut defined_in);
+    }
+
+    resolve_model_inheritance(&mut extracted.models);
+    monomorphize_generics(&mut extracted);
+
+    Ok(extracted)
+}
+
+/// Walk one root and fold every admitted file's types into `extracted`,
+/// warning on a name already defined elsewhere. Inheritance resolution and
+/// generic monomorphization are deferred to the caller so they run once over
+/// the fully merged set.
+fn merge_directory(
+    dir: &Path,
+    filter: &SourceFilter,
+    extracted: &mut ExtractedTypes,
+    defined_in: &mut HashMap<String, PathBuf>,
+) {
     for entry in WalkDir::new(dir)
         .into_iter()
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().map_or(false, |ext| ext == "py"))
+        .filter(|e| e.file_type().is_file() && filter.accepts(e.path()))
     {
         let path = entry.path();
         match parse_file(path) {
             Ok(types) => {
+                for name in types.models.keys().chain(types.enums.keys()) {
+                    if let Some(prev) = defined_in.get(name) {
+                        if prev != path {
+                            eprintln!(
+                                "Warning: '{}' is defined in both {} and {}; last one wins",
+                                name,
+                                prev.display(),
+                                path.display()
+                            );
+                        }
+                    } else {
+                        defined_in.insert(name.clone(), path.to_path_buf());
+                    }
+                }
                 extracted.models.extend(types.models);
                 extracted.enums.extend(types.enums);
                 extracted.routes.extend(types.routes);
+                extracted.type_vars.extend(types.type_vars);
+                extracted.imports.extend(types.imports);
             }
             Err(e) => {
                 eprintln!("Warning: Failed to parse {}: {}", path.display(), e);
             }
         }
     }
-    
-    Ok(extracted)
+}
+
+/// Persistent, incremental parse cache for the watch/codegen loop.
+///
+/// Keyed by source path, each entry remembers the file's content hash, the
+/// [`ExtractedTypes`] last produced for it, and the tree-sitter [`Tree`] so an
+/// edited file can be reparsed incrementally (`Tree::edit` + reuse) rather than
+/// from scratch, and an unchanged file skipped entirely.
+#[derive(Default)]
+pub struct ParseCache {
+    entries: HashMap<PathBuf, CacheEntry>,
+}
+
+struct CacheEntry {
+    hash: u64,
+    source: String,
+    tree: Tree,
+    types: ExtractedTypes,
+}
+
+impl ParseCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reparse a single file into the cache.
+    ///
+    /// Unchanged content (matching hash) is a no-op; an edited file is reparsed
+    /// incrementally from its cached tree; a new file is parsed from scratch.
+    /// Returns whether the file's extracted types were (re)built.
+    pub fn update(&mut self, path: &Path) -> Result<bool> {
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let hash = content_hash(&source);
+
+        match self.entries.get_mut(path) {
+            // Unchanged: reuse the cached tree and extracted types verbatim.
+            Some(existing) if existing.hash == hash => Ok(false),
+            // Edited: feed the old tree plus the byte-range edit to tree-sitter
+            // so only the affected subtrees are re-examined.
+            Some(existing) => {
+                // Edit a clone and parse against that, so a failed reparse (e.g.
+                // a transient syntax error while typing) leaves the cached
+                // `(source, tree)` pair consistent instead of a shifted tree
+                // paired with stale source.
+                let edit = compute_edit(&existing.source, &source);
+                let mut edited = existing.tree.clone();
+                edited.edit(&edit);
+                let (tree, types) = parse_source_tree(&source, Some(&edited))?;
+                *existing = CacheEntry { hash, source, tree, types };
+                Ok(true)
+            }
+            // New file: full parse.
+            None => {
+                let (tree, types) = parse_source_tree(&source, None)?;
+                self.entries
+                    .insert(path.to_path_buf(), CacheEntry { hash, source, tree, types });
+                Ok(true)
+            }
+        }
+    }
+
+    /// Drop a removed file's cached entry, returning whether one was present.
+    pub fn remove(&mut self, path: &Path) -> bool {
+        self.entries.remove(path).is_some()
+    }
+
+    /// Merge every cached file into one [`ExtractedTypes`].
+    ///
+    /// A type name defined in more than one file is a hard conflict rather than
+    /// a silent clobber, so the watch and codegen loops fail loudly instead of
+    /// emitting whichever definition happened to be merged last.
+    pub fn merged(&self) -> Result<ExtractedTypes> {
+        let mut extracted = ExtractedTypes::default();
+        let mut defined_in: HashMap<&str, &Path> = HashMap::new();
+
+        for (path, entry) in &self.entries {
+            for name in entry.types.models.keys().chain(entry.types.enums.keys()) {
+                if let Some(prev) = defined_in.insert(name.as_str(), path.as_path()) {
+                    if prev != path.as_path() {
+                        anyhow::bail!(
+                            "type '{}' is defined in both {} and {}",
+                            name,
+                            prev.display(),
+                            path.display()
+                        );
+                    }
+                }
+            }
+            extracted
+                .models
+                .extend(entry.types.models.iter().map(|(k, v)| (k.clone(), v.clone())));
+            extracted
+                .enums
+                .extend(entry.types.enums.iter().map(|(k, v)| (k.clone(), v.clone())));
+            extracted.routes.extend(entry.types.routes.iter().cloned());
+            extracted
+                .type_vars
+                .extend(entry.types.type_vars.iter().map(|(k, v)| (k.clone(), v.clone())));
+            extracted
+                .imports
+                .extend(entry.types.imports.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+
+        resolve_model_inheritance(&mut extracted.models);
+        monomorphize_generics(&mut extracted);
+        Ok(extracted)
+    }
+}
+
+/// Parse every `.py` file under `dir`, reusing `cache` for files whose contents
+/// are unchanged and incrementally reparsing the rest.
+///
+/// Returns the merged [`ExtractedTypes`] and the list of paths that actually
+/// changed (added, edited, or removed) since the cache was last populated, so a
+/// caller can skip regeneration entirely when nothing changed.
+#[allow(dead_code)]
+pub fn parse_directory_cached(
+    dir: &Path,
+    cache: &mut ParseCache,
+) -> Result<(ExtractedTypes, Vec<PathBuf>)> {
+    let mut changed = Vec::new();
+    let mut present: HashSet<PathBuf> = HashSet::new();
+
+    for entry in WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().map_or(false, |ext| ext == "py"))
+    {
+        let path = entry.path().to_path_buf();
+        present.insert(path.clone());
+        match cache.update(&path) {
+            Ok(true) => changed.push(path),
+            Ok(false) => {}
+            Err(e) => eprintln!("Warning: Failed to parse {}: {}", path.display(), e),
+        }
+    }
+
+    // Evict files that disappeared; their removal is itself a change.
+    let removed: Vec<PathBuf> = cache
+        .entries
+        .keys()
+        .filter(|p| !present.contains(*p))
+        .cloned()
+        .collect();
+    for path in &removed {
+        cache.remove(path);
+    }
+    changed.extend(removed);
+
+    let merged = cache.merged()?;
+    Ok((merged, changed))
+}
+
+/// Stable hash of a file's contents, used to decide whether a reparse is needed.
+fn content_hash(source: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    source.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Compute the minimal single-range [`InputEdit`] between `old` and `new` by
+/// stripping their common prefix and suffix, so tree-sitter can keep every
+/// subtree outside the changed span.
+fn compute_edit(old: &str, new: &str) -> InputEdit {
+    let old_bytes = old.as_bytes();
+    let new_bytes = new.as_bytes();
+
+    let shared = old_bytes.len().min(new_bytes.len());
+    let mut start = 0;
+    while start < shared && old_bytes[start] == new_bytes[start] {
+        start += 1;
+    }
+
+    // Common suffix length, without reaching back into the shared prefix.
+    let mut suffix = 0;
+    while suffix < shared - start
+        && old_bytes[old_bytes.len() - 1 - suffix] == new_bytes[new_bytes.len() - 1 - suffix]
+    {
+        suffix += 1;
+    }
+
+    let old_end = old_bytes.len() - suffix;
+    let new_end = new_bytes.len() - suffix;
+
+    InputEdit {
+        start_byte: start,
+        old_end_byte: old_end,
+        new_end_byte: new_end,
+        start_position: point_at(old, start),
+        old_end_position: point_at(old, old_end),
+        new_end_position: point_at(new, new_end),
+    }
+}
+
+/// The row/column [`Point`] at byte offset `byte` within `text`.
+fn point_at(text: &str, byte: usize) -> Point {
+    let clamped = byte.min(text.len());
+    let mut row = 0;
+    let mut column = 0;
+    for &b in &text.as_bytes()[..clamped] {
+        if b == b'\n' {
+            row += 1;
+            column = 0;
+        } else {
+            column += 1;
+        }
+    }
+    Point::new(row, column)
+}
+
+/// Merge inherited fields into subclasses so `class UserOut(UserBase)` carries
+/// every field declared on its bases.
+///
+/// Models are processed parents-before-children; for each model the parent
+/// fields are laid down first (left-to-right across multiple bases) and the
+/// model's own fields then override any same-named inherited field. Bases that
+/// are not themselves extracted models are treated as opaque external
+/// references and ignored, and inheritance cycles are broken so a malformed
+/// file cannot hang the resolver.
+fn resolve_model_inheritance(models: &mut HashMap<String, PydanticModel>) {
+    let order = inheritance_order(models);
+    for name in order {
+        let Some(model) = models.get(&name) else {
+            continue;
+        };
+        let bases = model.bases.clone();
+        let mut merged: Vec<ModelField> = Vec::new();
+        for base in &bases {
+            if let Some(parent) = models.get(base) {
+                for field in &parent.fields {
+                    upsert_field(&mut merged, field.clone());
+                }
+            }
+        }
+        // The model's own fields override inherited ones.
+        let own = models.get(&name).unwrap().fields.clone();
+        for field in own {
+            upsert_field(&mut merged, field);
+        }
+        models.get_mut(&name).unwrap().fields = merged;
+    }
+}
+
+/// Insert `field` into `fields`, replacing any existing field with the same
+/// name in place (so override order is preserved).
+fn upsert_field(fields: &mut Vec<ModelField>, field: ModelField) {
+    if let Some(existing) = fields.iter_mut().find(|f| f.name == field.name) {
+        *existing = field;
+    } else {
+        fields.push(field);
+    }
+}
+
+/// Produce a topological ordering of model names with parents before children.
+///
+/// Bases outside the model map are ignored. Cycles are broken by skipping the
+/// back-edge that closes them during the depth-first walk, guaranteeing
+/// termination on malformed inheritance graphs.
+fn inheritance_order(models: &HashMap<String, PydanticModel>) -> Vec<String> {
+    let mut visited: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut order: Vec<String> = Vec::new();
+    let mut names: Vec<&String> = models.keys().collect();
+    names.sort();
+
+    for name in names {
+        visit_model(name, models, &mut visited, &mut Vec::new(), &mut order);
+    }
+    order
+}
+
+/// Depth-first visit emitting a node after its in-map bases. `stack` tracks the
+/// current path so a back-edge (cycle) is detected and skipped.
+fn visit_model(
+    name: &str,
+    models: &HashMap<String, PydanticModel>,
+    visited: &mut std::collections::HashSet<String>,
+    stack: &mut Vec<String>,
+    order: &mut Vec<String>,
+) {
+    if visited.contains(name) || stack.iter().any(|n| n == name) {
+        return;
+    }
+    stack.push(name.to_string());
+    if let Some(model) = models.get(name) {
+        for base in &model.bases {
+            if models.contains_key(base) {
+                visit_model(base, models, visited, stack, order);
+            }
+        }
+    }
+    stack.pop();
+    visited.insert(name.to_string());
+    order.push(name.to_string());
 }
 
 /// Parse a single Python file
@@ -145,28 +695,161 @@ pub fn parse_file(path: &Path) -> Result<ExtractedTypes> {
 
 /// Parse Python source code
 pub fn parse_source(source: &str) -> Result<ExtractedTypes> {
+    let (_, extracted) = parse_source_tree(source, None)?;
+    Ok(extracted)
+}
+
+/// Build a fresh Python [`Parser`]. Shared by the one-shot and cached paths.
+fn python_parser() -> Result<Parser> {
     let mut parser = Parser::new();
     parser
         .set_language(&tree_sitter_python::language())
         .context("Failed to load Python grammar")?;
-    
+    Ok(parser)
+}
+
+/// Parse `source` into a syntax tree and the types extracted from it, optionally
+/// reusing `old_tree` for tree-sitter's incremental reparse. Returns the tree so
+/// callers (the cache) can keep it for the next edit.
+fn parse_source_tree(source: &str, old_tree: Option<&Tree>) -> Result<(Tree, ExtractedTypes)> {
+    let mut parser = python_parser()?;
+
     let tree = parser
-        .parse(source, None)
+        .parse(source, old_tree)
         .context("Failed to parse Python source")?;
-    
-    let mut extracted = ExtractedTypes::default();
+
+    // tree-sitter is error-tolerant: a half-typed or invalid file still yields a
+    // tree, but with ERROR nodes in it. Treat that as a hard parse failure so the
+    // watcher's on_error policy can keep the last good output instead of
+    // regenerating from a garbled, partial parse.
     let root = tree.root_node();
-    
+    if root.has_error() {
+        anyhow::bail!("syntax error in Python source");
+    }
+
+    let mut extracted = ExtractedTypes::default();
+
+    // Collect the file's import aliases first so later passes can resolve
+    // references through them.
+    extract_imports(&root, source.as_bytes(), &mut extracted);
+
+    // Record module-level TypeVar declarations (used by monomorphization).
+    extract_type_vars(&root, source.as_bytes(), &mut extracted)?;
+
     // Extract Python Enums
     extract_enums(&root, source.as_bytes(), &mut extracted)?;
-    
+
     // Extract Pydantic models (classes inheriting from BaseModel)
     extract_pydantic_models(&root, source.as_bytes(), &mut extracted)?;
-    
+
     // Extract FastAPI routes
     extract_fastapi_routes(&root, source.as_bytes(), &mut extracted)?;
-    
-    Ok(extracted)
+
+    // Rewrite aliased references (`X as Y`) to their canonical target so the
+    // merged output is collision-free regardless of local import names.
+    resolve_import_aliases(&mut extracted);
+
+    Ok((tree, extracted))
+}
+
+/// Collect a file's import aliases into [`ExtractedTypes::imports`].
+///
+/// Handles `from pkg import Name`, `from pkg import Name as Alias`, and
+/// `import pkg as alias`, mapping each locally-visible name to the canonical
+/// symbol (the imported name, or its last dotted segment) it refers to.
+fn extract_imports(root: &Node, source: &[u8], extracted: &mut ExtractedTypes) {
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        match child.kind() {
+            "import_from_statement" | "import_statement" => {
+                let text = child.utf8_text(source).unwrap_or("");
+                record_import_aliases(text, &mut extracted.imports);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parse a single import statement's text into `alias -> canonical` entries.
+fn record_import_aliases(stmt: &str, out: &mut HashMap<String, String>) {
+    let stmt = stmt.trim();
+    let names = if let Some(idx) = stmt.find(" import ") {
+        &stmt[idx + " import ".len()..]
+    } else if let Some(rest) = stmt.strip_prefix("import ") {
+        rest
+    } else {
+        return;
+    };
+
+    for item in names.split(',') {
+        let item = item.trim().trim_matches(|c| c == '(' || c == ')').trim();
+        if item.is_empty() || item == "*" {
+            continue;
+        }
+        let (target, alias) = match item.split_once(" as ") {
+            Some((t, a)) => (t.trim(), a.trim()),
+            None => (item, item),
+        };
+        // Reduce a dotted path to its final segment.
+        let canonical = target.rsplit('.').next().unwrap_or(target);
+        out.insert(alias.to_string(), canonical.to_string());
+    }
+}
+
+/// Rewrite every reference in the file through its import alias map, so an
+/// `X as Y` import makes `Y` resolve to `X` in the generated output.
+fn resolve_import_aliases(extracted: &mut ExtractedTypes) {
+    if extracted.imports.is_empty() {
+        return;
+    }
+    let imports = extracted.imports.clone();
+    for model in extracted.models.values_mut() {
+        for field in &mut model.fields {
+            field.py_type = rewrite_alias(&field.py_type, &imports);
+        }
+    }
+    for route in &mut extracted.routes {
+        if let Some(m) = &route.request_model {
+            route.request_model = Some(imports.get(m).cloned().unwrap_or_else(|| m.clone()));
+        }
+    }
+}
+
+/// Replace aliased reference names in a type through the alias map, recursing
+/// into containers. Only actual aliases (`alias != canonical`) are rewritten.
+fn rewrite_alias(ty: &PyType, imports: &HashMap<String, String>) -> PyType {
+    match ty {
+        PyType::Reference(name) => match imports.get(name) {
+            Some(canonical) if canonical != name => PyType::Reference(canonical.clone()),
+            _ => ty.clone(),
+        },
+        PyType::List(inner) => PyType::List(Box::new(rewrite_alias(inner, imports))),
+        PyType::Set(inner) => PyType::Set(Box::new(rewrite_alias(inner, imports))),
+        PyType::FrozenSet(inner) => PyType::FrozenSet(Box::new(rewrite_alias(inner, imports))),
+        PyType::Optional(inner) => PyType::Optional(Box::new(rewrite_alias(inner, imports))),
+        PyType::Dict(k, v) => PyType::Dict(
+            Box::new(rewrite_alias(k, imports)),
+            Box::new(rewrite_alias(v, imports)),
+        ),
+        PyType::Tuple(members) => {
+            PyType::Tuple(members.iter().map(|m| rewrite_alias(m, imports)).collect())
+        }
+        PyType::Union(members) => {
+            PyType::Union(members.iter().map(|m| rewrite_alias(m, imports)).collect())
+        }
+        PyType::GenericType(base, params) => {
+            let base = imports
+                .get(base)
+                .filter(|c| c.as_str() != base)
+                .cloned()
+                .unwrap_or_else(|| base.clone());
+            PyType::GenericType(
+                base,
+                params.iter().map(|p| rewrite_alias(p, imports)).collect(),
+            )
+        }
+        other => other.clone(),
+    }
 }
 
 /// Extract Python Enum definitions
@@ -282,6 +965,69 @@ fn extract_enum_variants(name: &str, body: &Node, source: &[u8]) -> Result<PyEnu
     })
 }
 
+/// Record module-level `T = TypeVar('T', bound=...)` declarations.
+///
+/// Only the optional `bound=` keyword is captured; positional constraints are
+/// ignored for now. The recorded bound is the fallback type substituted when a
+/// generic model is referenced without an explicit parameter.
+fn extract_type_vars(root: &Node, source: &[u8], extracted: &mut ExtractedTypes) -> Result<()> {
+    let query_str = r#"
+        (expression_statement
+            (assignment
+                left: (identifier) @name
+                right: (call
+                    function: (identifier) @func
+                    arguments: (argument_list) @args
+                )
+            )
+        )
+    "#;
+
+    let query = Query::new(&tree_sitter_python::language(), query_str)
+        .context("Failed to create TypeVar query")?;
+
+    let mut cursor = QueryCursor::new();
+    let matches = cursor.matches(&query, *root, source);
+
+    for m in matches {
+        let mut name = None;
+        let mut func = None;
+        let mut args = None;
+
+        for capture in m.captures {
+            let capture_name = query.capture_names()[capture.index as usize];
+            let text = capture.node.utf8_text(source).unwrap_or("");
+            match capture_name {
+                "name" => name = Some(text.to_string()),
+                "func" => func = Some(text),
+                "args" => args = Some(text),
+                _ => {}
+            }
+        }
+
+        if func == Some("TypeVar") {
+            if let Some(name) = name {
+                let bound = args.and_then(extract_bound).map(|b| parse_type_annotation(&b));
+                extracted.type_vars.insert(name, bound);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Pull the `bound=` argument out of a `TypeVar(...)` argument-list string.
+fn extract_bound(args: &str) -> Option<String> {
+    let idx = args.find("bound")?;
+    let rest = args[idx + "bound".len()..].trim_start();
+    let rest = rest.strip_prefix('=')?.trim_start();
+    let end = rest
+        .find(|c: char| c == ',' || c == ')')
+        .unwrap_or(rest.len());
+    let value = rest[..end].trim().trim_matches('"').trim_matches('\'');
+    (!value.is_empty()).then(|| value.to_string())
+}
+
 /// Extract Pydantic model definitions
 fn extract_pydantic_models(
     root: &Node,
@@ -294,40 +1040,46 @@ fn extract_pydantic_models(
             name: (identifier) @class_name
             superclasses: (argument_list
                 (identifier) @base_class
-            )?
+            )? @superclasses
             body: (block) @body
         ) @class
     "#;
-    
+
     let query = Query::new(&tree_sitter_python::language(), query_str)
         .context("Failed to create query")?;
-    
+
     let mut cursor = QueryCursor::new();
     let matches = cursor.matches(&query, *root, source);
-    
+
     for m in matches {
         let mut class_name = None;
         let mut is_pydantic = false;
         let mut body_node = None;
-        
+        let mut type_params = Vec::new();
+        let mut bases = Vec::new();
+
         for capture in m.captures {
             let capture_name = query.capture_names()[capture.index as usize];
             let text = capture.node.utf8_text(source).unwrap_or("");
-            
+
             match capture_name {
                 "class_name" => class_name = Some(text.to_string()),
                 "base_class" => {
                     if text == "BaseModel" || text == "BaseSettings" || text.ends_with("Model") {
                         is_pydantic = true;
                     }
+                    bases.push(text.to_string());
                 }
+                "superclasses" => type_params = extract_type_params(text),
                 "body" => body_node = Some(capture.node),
                 _ => {}
             }
         }
-        
+
         if let (Some(name), true, Some(body)) = (class_name, is_pydantic, body_node) {
-            let model = extract_model_fields(&name, &body, source)?;
+            let mut model = extract_model_fields(&name, &body, source)?;
+            model.type_params = type_params;
+            model.bases = bases;
             extracted.models.insert(name, model);
         }
     }
@@ -416,6 +1168,8 @@ fn extract_model_fields(name: &str, body: &Node, source: &[u8]) -> Result<Pydant
                         optional: false,
                         default: None,
                         description: None,
+                        alias: None,
+                        constraints: FieldConstraints::default(),
                     });
                 }
             }
@@ -426,9 +1180,251 @@ fn extract_model_fields(name: &str, body: &Node, source: &[u8]) -> Result<Pydant
         name: name.to_string(),
         fields,
         docstring,
+        type_params: Vec::new(),
+        bases: Vec::new(),
     })
 }
 
+/// Extract declared type parameters from a class's superclass list text.
+///
+/// `class Page(BaseModel, Generic[T])` yields `["T"]`; a `Generic[T, U]`
+/// base yields both in order. Returns empty when the class is not generic.
+fn extract_type_params(superclasses: &str) -> Vec<String> {
+    let Some(start) = superclasses.find("Generic[") else {
+        return Vec::new();
+    };
+    let rest = &superclasses[start + "Generic[".len()..];
+    let Some(end) = rest.find(']') else {
+        return Vec::new();
+    };
+    split_generic_args(&rest[..end])
+        .iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Monomorphize generic-model applications into concrete models.
+///
+/// For every `GenericType("Response", [User])` encountered in a field type,
+/// clone the generic template `Response`, substitute its declared type
+/// parameters positionally (`T -> User`), register the result under a mangled
+/// name (`ResponseUser`), and rewrite the referencing field to point at it.
+/// Identical instantiations are de-duplicated, nested instantiations are
+/// expanded transitively, and a type parameter left unbound falls back to its
+/// declared `TypeVar` bound (or `Any`).
+fn monomorphize_generics(extracted: &mut ExtractedTypes) {
+    let generics: HashMap<String, PydanticModel> = extracted
+        .models
+        .iter()
+        .filter(|(_, m)| !m.type_params.is_empty())
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    if generics.is_empty() {
+        return;
+    }
+
+    let type_vars = extracted.type_vars.clone();
+    let mut out_models: HashMap<String, PydanticModel> = HashMap::new();
+    let mut seen: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+    let names: Vec<String> = extracted.models.keys().cloned().collect();
+    let mut rewritten: HashMap<String, Vec<ModelField>> = HashMap::new();
+    for name in &names {
+        let model = &extracted.models[name];
+        let fields = model
+            .fields
+            .iter()
+            .map(|f| {
+                let mut nf = f.clone();
+                nf.py_type =
+                    rewrite_generic_type(&f.py_type, &generics, &type_vars, &mut out_models, &mut seen);
+                nf
+            })
+            .collect();
+        rewritten.insert(name.clone(), fields);
+    }
+
+    for (name, fields) in rewritten {
+        extracted.models.get_mut(&name).unwrap().fields = fields;
+    }
+    extracted.models.extend(out_models);
+}
+
+/// Rewrite a type, replacing any application of a generic template with a
+/// reference to its monomorphized form and recursing into containers.
+fn rewrite_generic_type(
+    ty: &PyType,
+    generics: &HashMap<String, PydanticModel>,
+    type_vars: &HashMap<String, Option<PyType>>,
+    out: &mut HashMap<String, PydanticModel>,
+    seen: &mut std::collections::HashSet<String>,
+) -> PyType {
+    match ty {
+        PyType::List(inner) => {
+            PyType::List(Box::new(rewrite_generic_type(inner, generics, type_vars, out, seen)))
+        }
+        PyType::Set(inner) => {
+            PyType::Set(Box::new(rewrite_generic_type(inner, generics, type_vars, out, seen)))
+        }
+        PyType::FrozenSet(inner) => {
+            PyType::FrozenSet(Box::new(rewrite_generic_type(inner, generics, type_vars, out, seen)))
+        }
+        PyType::Optional(inner) => {
+            PyType::Optional(Box::new(rewrite_generic_type(inner, generics, type_vars, out, seen)))
+        }
+        PyType::Dict(k, v) => PyType::Dict(
+            Box::new(rewrite_generic_type(k, generics, type_vars, out, seen)),
+            Box::new(rewrite_generic_type(v, generics, type_vars, out, seen)),
+        ),
+        PyType::Tuple(members) => PyType::Tuple(
+            members
+                .iter()
+                .map(|m| rewrite_generic_type(m, generics, type_vars, out, seen))
+                .collect(),
+        ),
+        PyType::Union(members) => PyType::Union(
+            members
+                .iter()
+                .map(|m| rewrite_generic_type(m, generics, type_vars, out, seen))
+                .collect(),
+        ),
+        PyType::GenericType(base, params) if generics.contains_key(base) => {
+            let args: Vec<PyType> = params
+                .iter()
+                .map(|p| rewrite_generic_type(p, generics, type_vars, out, seen))
+                .collect();
+            let mangled = instantiate_generic(base, &args, generics, type_vars, out, seen);
+            PyType::Reference(mangled)
+        }
+        PyType::GenericType(base, params) => PyType::GenericType(
+            base.clone(),
+            params
+                .iter()
+                .map(|p| rewrite_generic_type(p, generics, type_vars, out, seen))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Create (once) the concrete model for `base[args]` and return its mangled name.
+fn instantiate_generic(
+    base: &str,
+    args: &[PyType],
+    generics: &HashMap<String, PydanticModel>,
+    type_vars: &HashMap<String, Option<PyType>>,
+    out: &mut HashMap<String, PydanticModel>,
+    seen: &mut std::collections::HashSet<String>,
+) -> String {
+    let mangled = mangle_instance(base, args);
+    if !seen.insert(mangled.clone()) {
+        return mangled;
+    }
+
+    let template = &generics[base];
+    let mut subst: HashMap<String, PyType> = HashMap::new();
+    for (i, param) in template.type_params.iter().enumerate() {
+        let concrete = args.get(i).cloned().unwrap_or_else(|| {
+            type_vars
+                .get(param)
+                .cloned()
+                .flatten()
+                .unwrap_or(PyType::Any)
+        });
+        subst.insert(param.clone(), concrete);
+    }
+
+    let fields = template
+        .fields
+        .iter()
+        .map(|f| {
+            let substituted = substitute_type(&f.py_type, &subst);
+            let mut nf = f.clone();
+            // A substituted type may itself carry a generic application.
+            nf.py_type = rewrite_generic_type(&substituted, generics, type_vars, out, seen);
+            nf
+        })
+        .collect();
+
+    out.insert(
+        mangled.clone(),
+        PydanticModel {
+            name: mangled.clone(),
+            fields,
+            docstring: template.docstring.clone(),
+            type_params: Vec::new(),
+            bases: Vec::new(),
+        },
+    );
+    mangled
+}
+
+/// Substitute declared type parameters for concrete types throughout `ty`.
+fn substitute_type(ty: &PyType, subst: &HashMap<String, PyType>) -> PyType {
+    match ty {
+        PyType::Generic(name) | PyType::Reference(name) => {
+            subst.get(name).cloned().unwrap_or_else(|| ty.clone())
+        }
+        PyType::List(inner) => PyType::List(Box::new(substitute_type(inner, subst))),
+        PyType::Set(inner) => PyType::Set(Box::new(substitute_type(inner, subst))),
+        PyType::FrozenSet(inner) => PyType::FrozenSet(Box::new(substitute_type(inner, subst))),
+        PyType::Optional(inner) => PyType::Optional(Box::new(substitute_type(inner, subst))),
+        PyType::Dict(k, v) => PyType::Dict(
+            Box::new(substitute_type(k, subst)),
+            Box::new(substitute_type(v, subst)),
+        ),
+        PyType::Tuple(members) => {
+            PyType::Tuple(members.iter().map(|m| substitute_type(m, subst)).collect())
+        }
+        PyType::Union(members) => {
+            PyType::Union(members.iter().map(|m| substitute_type(m, subst)).collect())
+        }
+        PyType::GenericType(base, params) => PyType::GenericType(
+            base.clone(),
+            params.iter().map(|p| substitute_type(p, subst)).collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Build the mangled name for a generic instantiation, e.g. `Response` + `User`
+/// -> `ResponseUser`.
+fn mangle_instance(base: &str, args: &[PyType]) -> String {
+    let mut name = base.to_string();
+    for arg in args {
+        name.push_str(&mangle_component(arg));
+    }
+    name
+}
+
+/// A capitalized token summarizing a type, used to build readable mangled names.
+fn mangle_component(ty: &PyType) -> String {
+    match ty {
+        PyType::String => "Str".to_string(),
+        PyType::Int => "Int".to_string(),
+        PyType::Float => "Float".to_string(),
+        PyType::Bool => "Bool".to_string(),
+        PyType::Reference(name) | PyType::Generic(name) | PyType::Unknown(name) => name.clone(),
+        PyType::List(inner) => format!("List{}", mangle_component(inner)),
+        PyType::Set(inner) => format!("Set{}", mangle_component(inner)),
+        PyType::FrozenSet(inner) => format!("FrozenSet{}", mangle_component(inner)),
+        PyType::Optional(inner) => format!("Opt{}", mangle_component(inner)),
+        PyType::Dict(k, v) => format!("Dict{}{}", mangle_component(k), mangle_component(v)),
+        PyType::Tuple(members) | PyType::Union(members) => {
+            members.iter().map(mangle_component).collect()
+        }
+        PyType::GenericType(base, params) => {
+            let mut s = base.clone();
+            for p in params {
+                s.push_str(&mangle_component(p));
+            }
+            s
+        }
+        _ => "Any".to_string(),
+    }
+}
+
 /// Parse a type-annotated assignment node
 fn parse_annotated_assignment(node: &Node, source: &[u8]) -> Option<ModelField> {
     // Look for pattern: identifier: type = value
@@ -448,26 +1444,138 @@ fn parse_annotated_assignment(node: &Node, source: &[u8]) -> Option<ModelField>
     let type_node = children.iter().find(|n| n.kind() == "type")?;
     let type_str = type_node.utf8_text(source).ok()?;
     
-    // Check for default value
-    let default = children
+    // The value node after `=`, if any.
+    let value_node = children
         .iter()
-        .skip_while(|n| n.kind() != "=")
-        .nth(1)
-        .and_then(|n| n.utf8_text(source).ok())
-        .map(|s| s.to_string());
-    
+        .position(|n| n.kind() == "=")
+        .and_then(|i| children.get(i + 1).copied());
+
+    let mut default = None;
+    let mut description = None;
+    let mut alias = None;
+    let mut constraints = FieldConstraints::default();
+
+    if let Some(value) = value_node {
+        if is_field_call(&value, source) {
+            parse_field_call(
+                &value,
+                source,
+                &mut default,
+                &mut description,
+                &mut alias,
+                &mut constraints,
+            );
+        } else {
+            // A plain literal default behaves as before.
+            default = value.utf8_text(source).ok().map(|s| s.to_string());
+        }
+    }
+
     let py_type = parse_type_annotation(type_str);
     let optional = matches!(&py_type, PyType::Optional(_)) || default.is_some();
-    
+
     Some(ModelField {
         name,
         py_type,
         optional,
         default,
-        description: None,
+        description,
+        alias,
+        constraints,
     })
 }
 
+/// If `value` is a call to a Pydantic/FastAPI metadata constructor
+/// (`Field`, `Query`, `Path`, `Header`, `Cookie`, `Body`), return its name.
+///
+/// These share `Field`'s keyword vocabulary (`description=`, `ge=`, …), so the
+/// same [`parse_field_call`] walk populates a parameter's metadata regardless of
+/// which constructor was used.
+fn metadata_call_name(value: &Node, source: &[u8]) -> Option<String> {
+    if value.kind() != "call" {
+        return None;
+    }
+    let name = value
+        .child_by_field_name("function")?
+        .utf8_text(source)
+        .ok()?;
+    matches!(
+        name,
+        "Field" | "Query" | "Path" | "Header" | "Cookie" | "Body"
+    )
+    .then(|| name.to_string())
+}
+
+/// Whether a value node is a `Field(...)` call.
+fn is_field_call(value: &Node, source: &[u8]) -> bool {
+    value.kind() == "call"
+        && value
+            .child_by_field_name("function")
+            .and_then(|f| f.utf8_text(source).ok())
+            == Some("Field")
+}
+
+/// Walk a `Field(...)` call, populating default/description/alias/constraints.
+///
+/// The first positional argument sets the default unless it is `...`
+/// (Ellipsis), which marks the field as required; `default=`/`default_factory=`
+/// keywords do the same. Validation keywords populate [`FieldConstraints`].
+fn parse_field_call(
+    value: &Node,
+    source: &[u8],
+    default: &mut Option<String>,
+    description: &mut Option<String>,
+    alias: &mut Option<String>,
+    constraints: &mut FieldConstraints,
+) {
+    let Some(args) = value.child_by_field_name("arguments") else {
+        return;
+    };
+
+    let mut cursor = args.walk();
+    let mut seen_positional = false;
+    for arg in args.children(&mut cursor) {
+        match arg.kind() {
+            "," | "(" | ")" => {}
+            "keyword_argument" => {
+                let key = arg.child_by_field_name("name").and_then(|n| n.utf8_text(source).ok());
+                let raw = arg.child_by_field_name("value").and_then(|n| n.utf8_text(source).ok());
+                let (Some(key), Some(raw)) = (key, raw) else {
+                    continue;
+                };
+                match key {
+                    "description" => *description = Some(unquote(raw)),
+                    "alias" => *alias = Some(unquote(raw)),
+                    "default" if raw != "..." => *default = Some(raw.to_string()),
+                    "default_factory" => *default = Some(format!("{}()", raw)),
+                    "ge" => constraints.ge = raw.parse().ok(),
+                    "gt" => constraints.gt = raw.parse().ok(),
+                    "le" => constraints.le = raw.parse().ok(),
+                    "lt" => constraints.lt = raw.parse().ok(),
+                    "min_length" => constraints.min_length = raw.parse().ok(),
+                    "max_length" => constraints.max_length = raw.parse().ok(),
+                    "pattern" | "regex" => constraints.pattern = Some(unquote(raw)),
+                    _ => {}
+                }
+            }
+            // The first positional argument is the default (unless Ellipsis).
+            _ if !seen_positional => {
+                seen_positional = true;
+                let raw = arg.utf8_text(source).unwrap_or("");
+                if raw != "..." {
+                    *default = Some(raw.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Strip a single layer of matching quotes from a string literal.
+fn unquote(s: &str) -> String {
+    s.trim().trim_matches('"').trim_matches('\'').to_string()
+}
+
 /// Parse a simple typed field
 fn parse_typed_field(node: &Node, source: &[u8]) -> Option<ModelField> {
     let mut cursor = node.walk();
@@ -494,6 +1602,8 @@ fn parse_typed_field(node: &Node, source: &[u8]) -> Option<ModelField> {
         optional: false,
         default: None,
         description: None,
+        alias: None,
+        constraints: FieldConstraints::default(),
     })
 }
 
@@ -619,10 +1729,9 @@ pub fn parse_type_annotation(type_str: &str) -> PyType {
     
     // Handle Literal["a", "b"]
     if let Some(inner) = extract_generic(type_str, "Literal") {
-        let values: Vec<String> = inner
-            .split(',')
-            .map(|s| s.trim().trim_matches('"').trim_matches('\'').to_string())
-            .collect();
+        // Keep each member's raw text (quotes and all) so the renderer can tell
+        // string literals from numeric/boolean ones.
+        let values: Vec<String> = inner.split(',').map(|s| s.trim().to_string()).collect();
         return PyType::Literal(values);
     }
     
@@ -768,29 +1877,88 @@ fn extract_fastapi_routes(
                     .map(|s| s.trim_matches('"').trim_matches('\'').to_string())
                     .unwrap_or_else(|| format!("/{}", func_name));
                 
-                // Extract path parameters from path
-                let path_params: Vec<String> = path
+                // Extract path parameters, honoring `{name:converter}` syntax
+                // (`{file_path:path}`, `{id:int}`). The part before the colon is
+                // the name; the remainder, if any, is the converter.
+                let path_specs: Vec<(String, Option<String>)> = path
                     .split('/')
                     .filter(|s| s.starts_with('{') && s.ends_with('}'))
-                    .map(|s| s[1..s.len() - 1].to_string())
+                    .map(|s| {
+                        let inner = &s[1..s.len() - 1];
+                        match inner.split_once(':') {
+                            Some((name, conv)) => (name.to_string(), Some(conv.to_string())),
+                            None => (inner.to_string(), None),
+                        }
+                    })
                     .collect();
-                
-                // Extract request body model and query params from function parameters
-                let (request_model, query_params) = extract_route_params(
-                    params_node, 
-                    source, 
+                let path_param_names: Vec<String> =
+                    path_specs.iter().map(|(name, _)| name.clone()).collect();
+
+                // Normalize `{name:converter}` down to `{name}` in the stored
+                // path so URL templates and OpenAPI paths stay converter-free.
+                let mut path = path;
+                for (name, conv) in &path_specs {
+                    if let Some(conv) = conv {
+                        path = path.replace(
+                            &format!("{{{name}:{conv}}}"),
+                            &format!("{{{name}}}"),
+                        );
+                    }
+                }
+
+                // Read decorator keyword arguments (response_model, status_code,
+                // tags, deprecated); response_model overrides the return type.
+                let decorator = parse_decorator_kwargs(args_node, source);
+
+                // Classify function parameters into body/query/header/path.
+                let classified = extract_route_params(
+                    params_node,
+                    source,
                     &extracted.models,
-                    &path_params
+                    &path_param_names,
                 );
-                
+
+                // Cross-check declared path placeholders against typed params.
+                for placeholder in &path_param_names {
+                    if !classified.seen_params.contains(placeholder) {
+                        eprintln!(
+                            "Warning: path parameter '{{{}}}' in {} has no matching function argument",
+                            placeholder, func_name
+                        );
+                    }
+                }
+
+                // Give each path param a type: prefer the handler's annotation,
+                // then the converter suffix, defaulting to a string.
+                let path_params: Vec<PathParam> = path_specs
+                    .iter()
+                    .map(|(name, conv)| PathParam {
+                        name: name.clone(),
+                        py_type: classified
+                            .path_types
+                            .get(name)
+                            .cloned()
+                            .or_else(|| conv.as_deref().map(converter_type))
+                            .unwrap_or(PyType::String),
+                        wildcard: conv.as_deref() == Some("path"),
+                    })
+                    .collect();
+
                 extracted.routes.push(ApiRoute {
                     method,
                     path,
                     function_name: func_name,
-                    request_model,
-                    response_model: return_type,
-                    query_params,
+                    request_model: classified.request_model,
+                    response_model: decorator.response_model.or(return_type),
+                    query_params: classified.query_params,
                     path_params,
+                    header_params: classified.header_params,
+                    multi_value_query: classified.multi_value_query,
+                    dependencies: classified.dependencies,
+                    security_schemes: classified.security_schemes,
+                    status_code: decorator.status_code,
+                    tags: decorator.tags,
+                    deprecated: decorator.deprecated,
                 });
             }
         }
@@ -799,22 +1967,160 @@ fn extract_fastapi_routes(
     Ok(())
 }
 
-/// Extract request body model and query parameters from function parameters
-/// Returns (request_model, query_params)
+/// Keyword arguments read off a route decorator (`@router.post("/x", ...)`).
+#[derive(Default)]
+struct DecoratorKwargs {
+    response_model: Option<String>,
+    status_code: Option<String>,
+    tags: Vec<String>,
+    deprecated: bool,
+}
+
+/// Parse the decorator's keyword arguments, ignoring the leading path string.
+///
+/// `response_model=` and `status_code=` keep their expression text verbatim,
+/// `tags=[...]` is split into its string literals, and `deprecated=True` sets
+/// the flag.
+fn parse_decorator_kwargs(args_node: Option<Node>, source: &[u8]) -> DecoratorKwargs {
+    let mut kwargs = DecoratorKwargs::default();
+    let Some(args) = args_node else {
+        return kwargs;
+    };
+
+    let mut cursor = args.walk();
+    for arg in args.children(&mut cursor) {
+        if arg.kind() != "keyword_argument" {
+            continue;
+        }
+        let key = arg.child_by_field_name("name").and_then(|n| n.utf8_text(source).ok());
+        let raw = arg.child_by_field_name("value").and_then(|n| n.utf8_text(source).ok());
+        let (Some(key), Some(raw)) = (key, raw) else {
+            continue;
+        };
+        match key {
+            "response_model" => kwargs.response_model = Some(raw.to_string()),
+            "status_code" => kwargs.status_code = Some(raw.to_string()),
+            "deprecated" => kwargs.deprecated = raw == "True",
+            "tags" => {
+                kwargs.tags = raw
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .split(',')
+                    .map(|s| s.trim().trim_matches('"').trim_matches('\'').to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    kwargs
+}
+
+/// The result of classifying a handler's parameters by FastAPI convention.
+#[derive(Default)]
+struct ClassifiedParams {
+    request_model: Option<String>,
+    query_params: Vec<ModelField>,
+    header_params: Vec<ModelField>,
+    /// Every non-injected parameter name seen, used to cross-check path params.
+    seen_params: Vec<String>,
+    /// Annotated type of each parameter that is also a path placeholder, so the
+    /// caller can give the path param its real type instead of discarding it.
+    path_types: HashMap<String, PyType>,
+    /// Query param names that repeat (list-valued), mirrored onto the route.
+    multi_value_query: Vec<String>,
+    /// Callables injected via `Depends(...)`/`Security(...)`.
+    dependencies: Vec<String>,
+    /// Dependencies recognized as security schemes.
+    security_schemes: Vec<String>,
+}
+
+/// If `value` is a `Depends(...)` or `Security(...)` injection, return the
+/// referenced callable's name and whether it looks like a security scheme.
+fn depends_call(value: &Node, source: &[u8]) -> Option<(String, bool)> {
+    if value.kind() != "call" {
+        return None;
+    }
+    let func = value
+        .child_by_field_name("function")?
+        .utf8_text(source)
+        .ok()?;
+    if func != "Depends" && func != "Security" {
+        return None;
+    }
+    // The first positional argument is the dependency callable (bare name or
+    // dotted attribute); an empty `Depends()` uses the parameter's own type.
+    let args = value.child_by_field_name("arguments")?;
+    let mut cursor = args.walk();
+    let callable = args
+        .children(&mut cursor)
+        .find(|n| matches!(n.kind(), "identifier" | "attribute"))
+        .and_then(|n| n.utf8_text(source).ok())
+        .unwrap_or("")
+        .to_string();
+    let is_security = func == "Security" || is_security_scheme(&callable);
+    Some((callable, is_security))
+}
+
+/// Heuristic recognition of a security dependency by the callable's name.
+fn is_security_scheme(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    [
+        "oauth2",
+        "apikey",
+        "api_key",
+        "bearer",
+        "security",
+        "scheme",
+        "httpbasic",
+        "http_basic",
+    ]
+    .iter()
+    .any(|p| lower.contains(p))
+}
+
+/// Whether a query param accepts repeated values: a `List[..]` directly, or a
+/// `Union` with at least one list variant (`Union[int, List[int]]`).
+fn is_multi_value(py_type: &PyType) -> bool {
+    match py_type {
+        PyType::List(_) => true,
+        PyType::Union(members) => members.iter().any(is_multi_value),
+        _ => false,
+    }
+}
+
+/// Map a FastAPI/Starlette path converter (`int`, `str`, `float`, `uuid`,
+/// `path`) to the type it implies. Unknown converters default to a string.
+fn converter_type(conv: &str) -> PyType {
+    match conv {
+        "int" => PyType::Int,
+        "float" => PyType::Float,
+        "uuid" => PyType::UUID,
+        // `path` is a greedy string; `str` and anything else are plain strings.
+        _ => PyType::String,
+    }
+}
+
+/// Classify a handler's function parameters into body / query / header / path.
+///
+/// A parameter whose name matches a `{name}` placeholder in the decorator path
+/// is a path param (handled by the caller); one whose type is a known Pydantic
+/// model is the request body; `Header(...)` parameters become headers; and
+/// `Query(...)` parameters and simple scalar/`Optional`/`List`/`Union`-typed
+/// parameters (with or without a default) become query params.
 fn extract_route_params(
     params_node: Option<Node>,
     source: &[u8],
     models: &HashMap<String, PydanticModel>,
     path_params: &[String],
-) -> (Option<String>, Vec<ModelField>) {
+) -> ClassifiedParams {
+    let mut result = ClassifiedParams::default();
     let params = match params_node {
         Some(p) => p,
-        None => return (None, Vec::new()),
+        None => return result,
     };
-    
-    let mut request_model = None;
-    let mut query_params = Vec::new();
-    
+
     let mut cursor = params.walk();
     for child in params.children(&mut cursor) {
         // Look for typed parameters: (param_name: TypeName) or (param_name: TypeName = default)
@@ -825,9 +2131,7 @@ fn extract_route_params(
             let mut param_cursor = child.walk();
             let mut param_name = None;
             let mut param_type = None;
-            let mut is_query = false;
-            let mut is_optional = false;
-            
+
             for param_child in child.children(&mut param_cursor) {
                 match param_child.kind() {
                     "identifier" => {
@@ -842,62 +2146,136 @@ fn extract_route_params(
                 }
             }
             
-            // Check if it's a Query() parameter by looking at the default value
-            if param_text.contains("Query(") || param_text.contains("Query[") {
-                is_query = true;
-            }
-            
-            // Check if it has a default value (making it optional)
-            if param_text.contains("=") {
-                is_optional = true;
+            // Inspect the default value: a metadata constructor
+            // (`Query(...)`, `Path(...)`, `Field(...)`, `Header(...)`) both
+            // classifies the parameter and carries description/default/bounds.
+            let value_node = child.child_by_field_name("value");
+            let meta_kind = value_node
+                .as_ref()
+                .and_then(|v| metadata_call_name(v, source));
+            let depends = value_node.as_ref().and_then(|v| depends_call(v, source));
+
+            let mut default = None;
+            let mut description = None;
+            let mut alias = None;
+            let mut constraints = FieldConstraints::default();
+            if let Some(value) = value_node {
+                match meta_kind.as_deref() {
+                    Some(_) => parse_field_call(
+                        &value,
+                        source,
+                        &mut default,
+                        &mut description,
+                        &mut alias,
+                        &mut constraints,
+                    ),
+                    // A plain literal default (`limit: int = 10`).
+                    None => default = value.utf8_text(source).ok().map(|s| s.to_string()),
+                }
             }
-            
+
+            let is_query = meta_kind.as_deref() == Some("Query")
+                || param_text.contains("Query(")
+                || param_text.contains("Query[");
+            let is_header = meta_kind.as_deref() == Some("Header") || param_text.contains("Header(");
+
+            // A resolved default (not the `...` required marker) makes it optional.
+            let is_optional = default.is_some();
+
             if let (Some(name), Some(type_str)) = (param_name, param_type) {
                 // Skip 'self', 'request', 'response', and path params
                 if name == "self" || name == "request" || name == "response" {
                     continue;
                 }
-                
-                // Skip path parameters
+
+                result.seen_params.push(name.clone());
+
+                let type_str_clean = type_str.trim();
+
+                // Path parameters are handled by the caller, but keep their
+                // annotated type so the path param carries it.
                 if path_params.contains(&name) {
+                    result
+                        .path_types
+                        .insert(name, parse_type_annotation(type_str_clean));
                     continue;
                 }
-                
-                let type_str_clean = type_str.trim();
-                
+
+                // An injected dependency is supplied by the framework, not the
+                // caller: record it (flagging security schemes) and drop it from
+                // the client signature instead of leaking it as a query param.
+                if let Some((callable, is_security)) = depends {
+                    result.dependencies.push(callable.clone());
+                    if is_security {
+                        result.security_schemes.push(callable);
+                    }
+                    continue;
+                }
+
                 // Check if this type is a known Pydantic model (request body)
-                if models.contains_key(type_str_clean) && !is_query {
-                    request_model = Some(type_str_clean.to_string());
+                if models.contains_key(type_str_clean) && !is_query && !is_header {
+                    result.request_model = Some(type_str_clean.to_string());
                     continue;
                 }
-                
-                // If it's a Query param OR a simple type (not a model), treat as query param
-                // Simple types: str, int, float, bool, Optional[...], List[...]
+
+                let py_type = parse_type_annotation(type_str_clean);
+                let is_opt = matches!(&py_type, PyType::Optional(_)) || is_optional;
+                let field = ModelField {
+                    name,
+                    py_type,
+                    optional: is_opt,
+                    default,
+                    description,
+                    alias,
+                    constraints,
+                };
+
+                if is_header {
+                    result.header_params.push(field);
+                    continue;
+                }
+
+                // A Query param OR a simple scalar/collection becomes a query param.
                 let is_simple_type = matches!(
                     type_str_clean,
                     "str" | "int" | "float" | "bool" | "None"
                 ) || type_str_clean.starts_with("Optional")
                   || type_str_clean.starts_with("List")
                   || type_str_clean.starts_with("list")
+                  || type_str_clean.starts_with("Union")
                   || type_str_clean.contains(" | ");
-                
+
                 if is_query || is_simple_type {
-                    let py_type = parse_type_annotation(type_str_clean);
-                    let is_opt = matches!(&py_type, PyType::Optional(_)) || is_optional;
-                    
-                    query_params.push(ModelField {
-                        name,
-                        py_type,
-                        optional: is_opt,
-                        default: None,
-                        description: None,
-                    });
+                    // A list-valued (or list-in-union) query key repeats.
+                    if is_multi_value(&field.py_type) {
+                        result.multi_value_query.push(field.name.clone());
+                    }
+                    result.query_params.push(field);
+                }
+            }
+        } else if child.kind() == "identifier" || child.kind() == "default_parameter" {
+            // An untyped parameter (`def get_item(id):` or `id=0`) is a bare
+            // identifier rather than a `typed_parameter`. Record its name so the
+            // caller's path-placeholder cross-check recognizes it as a real
+            // argument instead of warning spuriously. Typing is left to the
+            // converter suffix or the string default.
+            let name = if child.kind() == "identifier" {
+                child.utf8_text(source).ok().map(|s| s.to_string())
+            } else {
+                child
+                    .child_by_field_name("name")
+                    .and_then(|n| n.utf8_text(source).ok())
+                    .map(|s| s.to_string())
+            };
+            if let Some(name) = name {
+                if name != "self" && name != "request" && name != "response" {
+                    result.seen_params.push(name);
                 }
             }
         }
     }
-    
-    (request_model, query_params)
+
+    result
 }
 
 #[cfg(test)]
@@ -929,6 +2307,349 @@ mod tests {
         assert!(matches!(result, PyType::Optional(inner) if matches!(*inner, PyType::String)));
     }
 
+    #[test]
+    fn test_extract_type_params() {
+        assert_eq!(
+            extract_type_params("BaseModel, Generic[T]"),
+            vec!["T".to_string()]
+        );
+        assert_eq!(
+            extract_type_params("Generic[T, U]"),
+            vec!["T".to_string(), "U".to_string()]
+        );
+        assert!(extract_type_params("BaseModel").is_empty());
+    }
+
+    #[test]
+    fn test_parse_generic_model() {
+        let source = r#"
+from pydantic import BaseModel
+from typing import Generic, TypeVar, List
+
+T = TypeVar("T")
+
+class Page(BaseModel, Generic[T]):
+    items: List[T]
+    total: int
+"#;
+        let result = parse_source(source).unwrap();
+        let page = &result.models["Page"];
+        assert_eq!(page.type_params, vec!["T".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_inheritance_merges_parent_fields() {
+        let mut models = HashMap::new();
+        models.insert(
+            "UserBase".to_string(),
+            PydanticModel {
+                name: "UserBase".to_string(),
+                fields: vec![
+                    ModelField {
+                        name: "name".to_string(),
+                        py_type: PyType::String,
+                        optional: false,
+                        default: None,
+                        description: None,
+                        alias: None,
+                        constraints: FieldConstraints::default(),
+                    },
+                    ModelField {
+                        name: "age".to_string(),
+                        py_type: PyType::Int,
+                        optional: true,
+                        default: None,
+                        description: None,
+                        alias: None,
+                        constraints: FieldConstraints::default(),
+                    },
+                ],
+                docstring: None,
+                type_params: Vec::new(),
+                bases: vec!["BaseModel".to_string()],
+            },
+        );
+        models.insert(
+            "UserOut".to_string(),
+            PydanticModel {
+                name: "UserOut".to_string(),
+                fields: vec![ModelField {
+                    name: "age".to_string(),
+                    py_type: PyType::Float,
+                    optional: false,
+                    default: None,
+                    description: None,
+                    alias: None,
+                    constraints: FieldConstraints::default(),
+                }],
+                docstring: None,
+                type_params: Vec::new(),
+                bases: vec!["UserBase".to_string()],
+            },
+        );
+
+        resolve_model_inheritance(&mut models);
+
+        let out = &models["UserOut"];
+        // Inherited `name`, plus own `age` overriding the parent's.
+        assert_eq!(out.fields.len(), 2);
+        assert_eq!(out.fields[0].name, "name");
+        let age = out.fields.iter().find(|f| f.name == "age").unwrap();
+        assert!(matches!(age.py_type, PyType::Float));
+    }
+
+    #[test]
+    fn test_resolve_inheritance_breaks_cycles() {
+        use crate::parser::ModelField;
+        let mut models = HashMap::new();
+        for (a, b) in [("A", "B"), ("B", "A")] {
+            models.insert(
+                a.to_string(),
+                PydanticModel {
+                    name: a.to_string(),
+                    fields: vec![ModelField {
+                        name: a.to_lowercase(),
+                        py_type: PyType::Int,
+                        optional: false,
+                        default: None,
+                        description: None,
+                        alias: None,
+                        constraints: FieldConstraints::default(),
+                    }],
+                    docstring: None,
+                    type_params: Vec::new(),
+                    bases: vec![b.to_string()],
+                },
+            );
+        }
+        // Must terminate despite the A <-> B cycle.
+        resolve_model_inheritance(&mut models);
+        assert!(models.contains_key("A"));
+        assert!(models.contains_key("B"));
+    }
+
+    #[test]
+    fn test_classify_route_params() {
+        let source = r#"
+from fastapi import APIRouter, Header, Query
+from pydantic import BaseModel
+
+router = APIRouter()
+
+class Item(BaseModel):
+    name: str
+
+@router.post("/items/{item_id}")
+def create_item(item_id: int, body: Item, limit: int = Query(10), x_token: str = Header(None)):
+    return body
+"#;
+        let result = parse_source(source).unwrap();
+        let route = result.routes.iter().find(|r| r.function_name == "create_item").unwrap();
+        assert_eq!(route.path_params.len(), 1);
+        assert_eq!(route.path_params[0].name, "item_id");
+        assert!(matches!(route.path_params[0].py_type, PyType::Int));
+        assert_eq!(route.request_model.as_deref(), Some("Item"));
+        assert!(route.query_params.iter().any(|p| p.name == "limit"));
+        assert!(route.header_params.iter().any(|p| p.name == "x_token"));
+    }
+
+    #[test]
+    fn test_path_converter_and_wildcard() {
+        let source = r#"
+from fastapi import APIRouter
+
+router = APIRouter()
+
+@router.get("/files/{file_path:path}")
+def serve(file_path: str):
+    ...
+
+@router.get("/items/{id:int}")
+def get_item(id):
+    ...
+"#;
+        let result = parse_source(source).unwrap();
+
+        let serve = result.routes.iter().find(|r| r.function_name == "serve").unwrap();
+        // Converter is stripped from the stored path.
+        assert_eq!(serve.path, "/files/{file_path}");
+        assert_eq!(serve.path_params[0].name, "file_path");
+        assert!(serve.path_params[0].wildcard);
+
+        let get_item = result.routes.iter().find(|r| r.function_name == "get_item").unwrap();
+        // No annotation on the argument, so the `:int` converter supplies the type.
+        assert_eq!(get_item.path, "/items/{id}");
+        assert!(matches!(get_item.path_params[0].py_type, PyType::Int));
+    }
+
+    #[test]
+    fn test_decorator_kwargs() {
+        let source = r#"
+from fastapi import APIRouter
+from pydantic import BaseModel
+
+router = APIRouter()
+
+class User(BaseModel):
+    name: str
+
+@router.post("/users", response_model=User, status_code=201, tags=["users", "admin"], deprecated=True)
+def make_user() -> dict:
+    ...
+"#;
+        let result = parse_source(source).unwrap();
+        let route = result.routes.iter().find(|r| r.function_name == "make_user").unwrap();
+        // response_model keyword wins over the `-> dict` return annotation.
+        assert_eq!(route.response_model.as_deref(), Some("User"));
+        assert_eq!(route.status_code.as_deref(), Some("201"));
+        assert_eq!(route.tags, vec!["users".to_string(), "admin".to_string()]);
+        assert!(route.deprecated);
+    }
+
+    #[test]
+    fn test_depends_and_security_dependencies() {
+        let source = r#"
+from fastapi import APIRouter, Depends
+
+router = APIRouter()
+
+@router.get("/me")
+def read_me(db: Session = Depends(get_db), token: str = Depends(oauth2_scheme)):
+    ...
+"#;
+        let result = parse_source(source).unwrap();
+        let route = result.routes.iter().find(|r| r.function_name == "read_me").unwrap();
+
+        // Neither injected param leaks into the query set.
+        assert!(route.query_params.is_empty());
+        assert!(route.dependencies.contains(&"get_db".to_string()));
+        assert!(route.dependencies.contains(&"oauth2_scheme".to_string()));
+        // Only the OAuth2 dependency is flagged as a security scheme.
+        assert_eq!(route.security_schemes, vec!["oauth2_scheme".to_string()]);
+    }
+
+    #[test]
+    fn test_query_param_field_metadata() {
+        let source = r#"
+from fastapi import APIRouter, Query
+
+router = APIRouter()
+
+@router.get("/search")
+def search(q: str = Query("all", description="search term", min_length=2, max_length=50)):
+    ...
+"#;
+        let result = parse_source(source).unwrap();
+        let route = result.routes.iter().find(|r| r.function_name == "search").unwrap();
+        let q = route.query_params.iter().find(|p| p.name == "q").unwrap();
+        assert_eq!(q.description.as_deref(), Some("search term"));
+        assert_eq!(q.default.as_deref(), Some("\"all\""));
+        assert!(q.optional);
+        assert_eq!(q.constraints.min_length, Some(2));
+        assert_eq!(q.constraints.max_length, Some(50));
+    }
+
+    #[test]
+    fn test_list_and_union_query_params() {
+        let source = r#"
+from fastapi import APIRouter
+from typing import List, Union
+
+router = APIRouter()
+
+@router.get("/items")
+def list_items(id_list: List[int], q: Union[int, List[int]]):
+    ...
+"#;
+        let result = parse_source(source).unwrap();
+        let route = result.routes.iter().find(|r| r.function_name == "list_items").unwrap();
+
+        let id_list = route.query_params.iter().find(|p| p.name == "id_list").unwrap();
+        assert!(matches!(id_list.py_type, PyType::List(_)));
+
+        let q = route.query_params.iter().find(|p| p.name == "q").unwrap();
+        assert!(matches!(q.py_type, PyType::Union(_)));
+
+        // Both repeat: a bare list, and a union containing a list.
+        assert!(route.multi_value_query.contains(&"id_list".to_string()));
+        assert!(route.multi_value_query.contains(&"q".to_string()));
+    }
+
+    #[test]
+    fn test_parse_field_metadata() {
+        let source = r#"
+from pydantic import BaseModel, Field
+
+class Item(BaseModel):
+    count: int = Field(default=0, ge=1, le=100, description="How many", alias="userId")
+    name: str = Field(..., min_length=1, max_length=50)
+"#;
+        let result = parse_source(source).unwrap();
+        let item = &result.models["Item"];
+
+        let count = item.fields.iter().find(|f| f.name == "count").unwrap();
+        assert_eq!(count.description.as_deref(), Some("How many"));
+        assert_eq!(count.alias.as_deref(), Some("userId"));
+        assert_eq!(count.default.as_deref(), Some("0"));
+        assert_eq!(count.constraints.ge, Some(1.0));
+        assert_eq!(count.constraints.le, Some(100.0));
+        assert!(count.optional);
+
+        let name = item.fields.iter().find(|f| f.name == "name").unwrap();
+        // `...` (Ellipsis) means required: no default.
+        assert!(name.default.is_none());
+        assert_eq!(name.constraints.min_length, Some(1));
+        assert_eq!(name.constraints.max_length, Some(50));
+    }
+
+    #[test]
+    fn test_import_alias_resolution() {
+        let source = r#"
+from pydantic import BaseModel
+from .models import User as Account
+
+class Wrapper(BaseModel):
+    owner: Account
+"#;
+        let result = parse_source(source).unwrap();
+        assert_eq!(result.imports.get("Account"), Some(&"User".to_string()));
+        let wrapper = &result.models["Wrapper"];
+        let owner = &wrapper.fields[0];
+        assert!(matches!(&owner.py_type, PyType::Reference(n) if n == "User"));
+    }
+
+    #[test]
+    fn test_monomorphize_generic_model() {
+        let source = r#"
+from pydantic import BaseModel
+from typing import Generic, TypeVar
+
+T = TypeVar("T")
+
+class Response(BaseModel, Generic[T]):
+    data: T
+    ok: bool
+
+class User(BaseModel):
+    name: str
+
+class Envelope(BaseModel):
+    result: Response[User]
+"#;
+        let mut result = parse_source(source).unwrap();
+        monomorphize_generics(&mut result);
+
+        // A concrete ResponseUser was registered.
+        let concrete = result.models.get("ResponseUser").expect("ResponseUser");
+        let data = concrete.fields.iter().find(|f| f.name == "data").unwrap();
+        assert!(matches!(&data.py_type, PyType::Reference(n) if n == "User"));
+
+        // Envelope now points at the concrete instance.
+        let envelope = &result.models["Envelope"];
+        let field = &envelope.fields[0];
+        assert!(matches!(&field.py_type, PyType::Reference(n) if n == "ResponseUser"));
+    }
+
     #[test]
     fn test_parse_pydantic_model() {
         let source = r#"
@@ -945,4 +2666,111 @@ class User(BaseModel):
         let user = &result.models["User"];
         assert_eq!(user.fields.len(), 3);
     }
+
+    #[test]
+    fn test_parse_directory_cached_skips_unchanged() {
+        let temp = tempfile::tempdir().unwrap();
+        let file = temp.path().join("models.py");
+        fs::write(
+            &file,
+            "from pydantic import BaseModel\n\nclass User(BaseModel):\n    name: str\n",
+        )
+        .unwrap();
+
+        let mut cache = ParseCache::new();
+
+        // First pass: the file is new, so it is reported as changed.
+        let (types, changed) = parse_directory_cached(temp.path(), &mut cache).unwrap();
+        assert!(types.models.contains_key("User"));
+        assert_eq!(changed, vec![file.clone()]);
+
+        // Second pass with no edits: nothing is reported.
+        let (_, changed) = parse_directory_cached(temp.path(), &mut cache).unwrap();
+        assert!(changed.is_empty());
+
+        // Editing the file makes it reparse incrementally and surface again.
+        fs::write(
+            &file,
+            "from pydantic import BaseModel\n\nclass User(BaseModel):\n    name: str\n    age: int\n",
+        )
+        .unwrap();
+        let (types, changed) = parse_directory_cached(temp.path(), &mut cache).unwrap();
+        assert_eq!(changed, vec![file.clone()]);
+        assert_eq!(types.models["User"].fields.len(), 2);
+
+        // Removing the file evicts it and reports the removal.
+        fs::remove_file(&file).unwrap();
+        let (types, changed) = parse_directory_cached(temp.path(), &mut cache).unwrap();
+        assert_eq!(changed, vec![file]);
+        assert!(types.models.is_empty());
+    }
+
+    #[test]
+    fn test_parse_sources_merges_roots_and_skips_optional_missing() {
+        let temp = tempfile::tempdir().unwrap();
+        let models = temp.path().join("models");
+        let routes = temp.path().join("routes");
+        fs::create_dir_all(&models).unwrap();
+        fs::create_dir_all(&routes).unwrap();
+        fs::write(
+            models.join("user.py"),
+            "from pydantic import BaseModel\n\nclass User(BaseModel):\n    name: str\n",
+        )
+        .unwrap();
+        fs::write(
+            routes.join("order.py"),
+            "from pydantic import BaseModel\n\nclass Order(BaseModel):\n    total: int\n",
+        )
+        .unwrap();
+
+        let sources = vec![
+            SourceDir::Path(models),
+            SourceDir::Path(routes),
+            // Optional, absent: skipped rather than fatal.
+            SourceDir::Detailed {
+                path: temp.path().join("missing"),
+                required: false,
+            },
+        ];
+        let include = vec!["**/*.py".to_string()];
+        let types = parse_sources(&sources, &include, &[]).unwrap();
+        assert!(types.models.contains_key("User"));
+        assert!(types.models.contains_key("Order"));
+
+        // A missing required root is a hard error.
+        let required_missing = vec![SourceDir::Path(temp.path().join("nope"))];
+        assert!(parse_sources(&required_missing, &include, &[]).is_err());
+    }
+
+    #[test]
+    fn test_source_filter_include_exclude_and_gitignore() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        fs::write(root.join(".gitignore"), "vendor/\n").unwrap();
+
+        let filter = SourceFilter::new(
+            root,
+            &["**/*.py".to_string()],
+            &["**/test_*.py".to_string()],
+        )
+        .unwrap();
+
+        assert!(filter.accepts(&root.join("models.py")));
+        // Excluded by the exclude glob.
+        assert!(!filter.accepts(&root.join("test_models.py")));
+        // Not a Python file.
+        assert!(!filter.accepts(&root.join("README.md")));
+        // Ignored by .gitignore.
+        assert!(!filter.accepts(&root.join("vendor/dep.py")));
+    }
+
+    #[test]
+    fn test_compute_edit_locates_changed_span() {
+        let old = "name: str\nage: int\n";
+        let new = "name: str\nage: num\n";
+        let edit = compute_edit(old, new);
+        assert_eq!(edit.start_byte, 15); // up to "age: "
+        assert_eq!(&old[edit.start_byte..edit.old_end_byte], "int");
+        assert_eq!(&new[edit.start_byte..edit.new_end_byte], "num");
+    }
 }
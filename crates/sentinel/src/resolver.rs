@@ -0,0 +1,157 @@
+//! Symbol resolution across models and enums.
+//!
+//! Before codegen emits a `PyType::Reference(name)` verbatim, this pass builds
+//! a symbol table from every parsed model and enum, walks all field types,
+//! route params, and request/response models, and reports any reference that
+//! resolves to nothing (e.g. a model imported from another module that was
+//! never parsed). It also detects name collisions between an enum and a model,
+//! and exposes the resolved set so later passes can rely on it.
+
+use crate::diagnostics::DiagnosticCollector;
+use crate::parser::{ExtractedTypes, PyType};
+use std::collections::HashMap;
+
+/// Whether a resolved symbol is a model or an enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Model,
+    Enum,
+}
+
+/// The set of names that resolve to a real generated type.
+#[derive(Debug, Default)]
+pub struct SymbolTable {
+    symbols: HashMap<String, SymbolKind>,
+}
+
+impl SymbolTable {
+    /// Whether `name` resolves to a known model or enum.
+    pub fn contains(&self, name: &str) -> bool {
+        self.symbols.contains_key(name)
+    }
+
+    /// The resolved kind for `name`, if any.
+    pub fn kind(&self, name: &str) -> Option<SymbolKind> {
+        self.symbols.get(name).copied()
+    }
+}
+
+/// Build the symbol table and report dangling references and collisions.
+///
+/// Returns the resolved set so downstream passes can trust that any name it
+/// contains will be emitted.
+pub fn resolve(types: &ExtractedTypes, diags: &mut DiagnosticCollector) -> SymbolTable {
+    let mut table = SymbolTable::default();
+
+    // Enums first, then models, so a model/enum collision is reported once.
+    for name in types.enums.keys() {
+        table.symbols.insert(name.clone(), SymbolKind::Enum);
+    }
+    for name in types.models.keys() {
+        if table.symbols.get(name) == Some(&SymbolKind::Enum) {
+            diags.error(
+                format!("name collision: '{name}' is defined as both an enum and a model"),
+                None,
+            );
+        }
+        table.symbols.insert(name.clone(), SymbolKind::Model);
+    }
+
+    // Built-in type-variable-like single uppercase letters are never
+    // references to a model; skip them when checking.
+    let mut check = |name: &str, ctx: &str, diags: &mut DiagnosticCollector| {
+        if !table.contains(name) && !is_type_var(name) {
+            diags.warn(format!("dangling reference '{name}' in {ctx}"), None);
+        }
+    };
+
+    for model in types.models.values() {
+        for field in &model.fields {
+            let ctx = format!("{}.{}", model.name, field.name);
+            for name in referenced_names(&field.py_type) {
+                check(&name, &ctx, diags);
+            }
+        }
+    }
+
+    for route in &types.routes {
+        if let Some(model) = &route.request_model {
+            for name in identifiers_in(model) {
+                check(&name, &format!("{} request body", route.function_name), diags);
+            }
+        }
+        if let Some(model) = &route.response_model {
+            for name in identifiers_in(model) {
+                check(&name, &format!("{} response", route.function_name), diags);
+            }
+        }
+        for param in &route.query_params {
+            let ctx = format!("{} query param `{}`", route.function_name, param.name);
+            for name in referenced_names(&param.py_type) {
+                check(&name, &ctx, diags);
+            }
+        }
+        for param in &route.path_params {
+            let ctx = format!("{} path param `{}`", route.function_name, param.name);
+            for name in referenced_names(&param.py_type) {
+                check(&name, &ctx, diags);
+            }
+        }
+    }
+
+    table
+}
+
+/// A single uppercase letter (`T`, `K`, `V`) is treated as a type variable,
+/// not a model reference.
+fn is_type_var(name: &str) -> bool {
+    name.len() == 1 && name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+/// Collect every model/enum name a type refers to, recursing into containers.
+fn referenced_names(py_type: &PyType) -> Vec<String> {
+    let mut names = Vec::new();
+    collect_names(py_type, &mut names);
+    names
+}
+
+fn collect_names(py_type: &PyType, out: &mut Vec<String>) {
+    match py_type {
+        PyType::Reference(name) => out.push(name.clone()),
+        PyType::List(inner)
+        | PyType::Set(inner)
+        | PyType::FrozenSet(inner)
+        | PyType::Optional(inner) => collect_names(inner, out),
+        PyType::Dict(key, value) => {
+            collect_names(key, out);
+            collect_names(value, out);
+        }
+        PyType::Tuple(members) | PyType::Union(members) => {
+            for member in members {
+                collect_names(member, out);
+            }
+        }
+        PyType::GenericType(base, params) => {
+            out.push(base.clone());
+            for param in params {
+                collect_names(param, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Extract capitalized identifiers from a raw type string such as
+/// `List[User]` or `Page[Item]`, ignoring known container keywords.
+fn identifiers_in(raw: &str) -> Vec<String> {
+    raw.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|s| s.chars().next().is_some_and(|c| c.is_ascii_uppercase()))
+        .filter(|s| {
+            !matches!(
+                *s,
+                "List" | "Dict" | "Set" | "FrozenSet" | "Tuple" | "Optional" | "Union" | "Literal"
+            )
+        })
+        .map(|s| s.to_string())
+        .collect()
+}
@@ -3,78 +3,200 @@
 //! Uses the `notify` crate to watch for file changes and trigger
 //! type regeneration in <50ms.
 
-use crate::config::Config;
+use crate::config::{Config, OnError};
 use crate::generator;
-use crate::parser;
+use crate::parser::{ParseCache, SourceFilter};
 use anyhow::Result;
 use colored::Colorize;
+use futures_util::{SinkExt, StreamExt};
 use notify::RecursiveMode;
 use notify_debouncer_mini::new_debouncer;
+use serde_json::json;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::channel;
 use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message;
 
-/// Start watching Python files and regenerating TypeScript definitions
-pub async fn watch(config: Config) -> Result<()> {
-    // Initial generation
-    regenerate(&config)?;
-    
-    // Set up file watcher with debouncing
+/// Counts and timing from one regeneration pass, reused for the live-reload
+/// broadcast payload.
+struct RegenStats {
+    duration: Duration,
+    models: usize,
+    routes: usize,
+}
+
+/// Start watching Python files and regenerating TypeScript definitions.
+///
+/// When `serve` carries a port, a live-reload WebSocket server runs alongside
+/// the watcher and the generated TypeScript gains a client runtime; each
+/// successful regeneration is broadcast to connected browsers. A compact
+/// header is printed before each regeneration cycle; when `clear_screen` is
+/// set, the terminal is cleared first.
+pub async fn watch(config: Config, serve: Option<u16>, clear_screen: bool) -> Result<()> {
+    // Resolve each source root to its own filter. A required root that is
+    // missing aborts; an optional one is skipped with a warning, mirroring
+    // `parser::parse_sources`.
+    let mut roots: Vec<(PathBuf, SourceFilter)> = Vec::new();
+    for source in &config.python.source_dirs {
+        let dir = source.path();
+        if !dir.is_dir() {
+            if source.required() {
+                anyhow::bail!("required source directory does not exist: {}", dir.display());
+            }
+            eprintln!(
+                "{} skipping missing optional source directory {}",
+                "⚠".yellow(),
+                dir.display()
+            );
+            continue;
+        }
+        let filter = SourceFilter::new(dir, &config.python.include, &config.python.exclude)?;
+        roots.push((dir.to_path_buf(), filter));
+    }
+
+    // Start the live-reload server, holding onto the broadcast sender so the
+    // event loop can push updates after each regeneration.
+    let broadcaster = match serve {
+        Some(port) => {
+            let (tx, _rx) = broadcast::channel::<String>(64);
+            let server_tx = tx.clone();
+            tokio::spawn(async move {
+                if let Err(e) = serve_live_reload(port, server_tx).await {
+                    eprintln!("{} Live-reload server error: {}", "✗".red(), e);
+                }
+            });
+            println!(
+                "{} Live-reload server on {}",
+                "→".blue(),
+                format!("ws://localhost:{port}").cyan()
+            );
+            Some(tx)
+        }
+        None => None,
+    };
+
+    // Seed the per-file cache and do the initial full generation.
+    let mut cache = ParseCache::new();
+    for (dir, filter) in &roots {
+        seed_cache(&mut cache, dir, filter);
+    }
+    regenerate(&config, &cache, serve)?;
+
+    // Set up file watcher with a debounce window coalescing rapid saves, and
+    // register one watch per source root.
     let (tx, rx) = channel();
-    
-    // 50ms debounce - fast enough to feel instant, slow enough to batch rapid saves
-    let mut debouncer = new_debouncer(Duration::from_millis(50), tx)?;
-    
-    debouncer.watcher().watch(
-        &config.python.source_dir,
-        RecursiveMode::Recursive,
-    )?;
-    
+    let debounce = Duration::from_millis(config.watch.debounce_ms);
+    let mut debouncer = new_debouncer(debounce, tx)?;
+
+    for (dir, _) in &roots {
+        debouncer.watcher().watch(dir, RecursiveMode::Recursive)?;
+    }
+
     println!(
         "{} Watching for changes... (Ctrl+C to stop)",
         "→".blue()
     );
-    
+
     // Event loop
     loop {
         match rx.recv() {
             Ok(Ok(events)) => {
-                // Filter for Python file changes
+                // Keep only paths admitted by the filter of the root that owns
+                // them (each root has its own include/exclude/.gitignore).
                 let python_changes: Vec<_> = events
                     .iter()
-                    .filter(|e| {
-                        e.path
-                            .extension()
-                            .map_or(false, |ext| ext == "py")
-                    })
+                    .filter(|e| owning_filter(&roots, &e.path).is_some_and(|f| f.accepts(&e.path)))
                     .collect();
-                
-                if !python_changes.is_empty() {
-                    // Show which files changed
-                    for event in &python_changes {
-                        let relative_path = event
-                            .path
-                            .strip_prefix(&config.python.source_dir)
-                            .unwrap_or(&event.path);
-                        println!(
-                            "{} {}",
-                            "⚡".bright_yellow(),
-                            relative_path.display().to_string().cyan()
-                        );
+
+                // Nothing of interest changed — stay silent, don't regenerate.
+                if python_changes.is_empty() {
+                    continue;
+                }
+
+                // Start a fresh cycle: optionally clear the terminal and print
+                // a compact header so stale output never piles up.
+                render_header(clear_screen, python_changes.len());
+
+                // Apply each change to the per-file cache (incremental reparse).
+                let mut parse_failed = None;
+                let mut failed_paths: Vec<PathBuf> = Vec::new();
+                for event in &python_changes {
+                    println!(
+                        "{} {}",
+                        "⚡".bright_yellow(),
+                        display_path(&roots, &event.path).cyan()
+                    );
+
+                    if event.path.exists() {
+                        // Guard against a malformed file panicking the parser so
+                        // a single bad save can never kill the watch process.
+                        match std::panic::catch_unwind(AssertUnwindSafe(|| cache.update(&event.path)))
+                        {
+                            Ok(Ok(_)) => {}
+                            Ok(Err(e)) => {
+                                parse_failed = Some(e);
+                                failed_paths.push(event.path.clone());
+                            }
+                            Err(_) => {
+                                parse_failed = Some(anyhow::anyhow!(
+                                    "parser panicked on {}",
+                                    event.path.display()
+                                ));
+                                failed_paths.push(event.path.clone());
+                            }
+                        }
+                    } else {
+                        cache.remove(&event.path);
                     }
-                    
-                    // Regenerate types
-                    match regenerate(&config) {
-                        Ok(duration) => {
-                            println!(
-                                "{} Types updated in {}",
-                                "✓".green(),
-                                format!("{}ms", duration.as_millis()).bright_yellow()
-                            );
+                }
+
+                // On a parse failure, honor the on_error policy: keep the last
+                // good output (default) or clear the failing file's symbols.
+                if let Some(e) = parse_failed {
+                    match config.watch.on_error {
+                        OnError::Keep => {
+                            eprintln!("{} {} (keeping last good output)", "✗".red(), e);
+                            continue;
+                        }
+                        OnError::Clear => {
+                            eprintln!("{} {} (clearing stale symbols)", "✗".red(), e);
+                            for path in &failed_paths {
+                                cache.remove(path);
+                            }
                         }
-                        Err(e) => {
-                            eprintln!("{} {}", "✗".red(), e);
+                    }
+                }
+
+                match regenerate(&config, &cache, serve) {
+                    Ok(stats) => {
+                        println!(
+                            "{} Types updated in {}",
+                            "✓".green(),
+                            format!("{}ms", stats.duration.as_millis()).bright_yellow()
+                        );
+                        // Notify connected browsers which files changed.
+                        if let Some(tx) = &broadcaster {
+                            let changed: Vec<String> = python_changes
+                                .iter()
+                                .map(|e| display_path(&roots, &e.path))
+                                .collect();
+                            let payload = json!({
+                                "type": "types-updated",
+                                "changed": changed,
+                                "models": stats.models,
+                                "routes": stats.routes,
+                                "durationMs": stats.duration.as_millis() as u64,
+                            });
+                            // Ignore send errors: they just mean no clients.
+                            let _ = tx.send(payload.to_string());
                         }
                     }
+                    Err(e) => {
+                        eprintln!("{} {}", "✗".red(), e);
+                    }
                 }
             }
             Ok(Err(error)) => {
@@ -86,42 +208,142 @@ pub async fn watch(config: Config) -> Result<()> {
             }
         }
     }
-    
+
     Ok(())
 }
 
-/// Regenerate TypeScript definitions from Python source
-fn regenerate(config: &Config) -> Result<Duration> {
+/// Begin a regeneration cycle: clear the terminal (when enabled) and print a
+/// one-line header summarizing how many files changed.
+fn render_header(clear_screen: bool, changed: usize) {
+    if clear_screen {
+        let _ = clearscreen::clear();
+    }
+    let label = if changed == 1 { "change" } else { "changes" };
+    println!(
+        "{} PolyRPC — {} {}",
+        "⚡".bright_yellow(),
+        changed.to_string().bright_cyan(),
+        label
+    );
+}
+
+/// Find the filter of the source root that contains `path`, if any.
+fn owning_filter<'a>(
+    roots: &'a [(PathBuf, SourceFilter)],
+    path: &Path,
+) -> Option<&'a SourceFilter> {
+    roots
+        .iter()
+        .find(|(dir, _)| path.starts_with(dir))
+        .map(|(_, filter)| filter)
+}
+
+/// Render `path` relative to its owning source root for display, falling back
+/// to the full path when it sits outside every root.
+fn display_path(roots: &[(PathBuf, SourceFilter)], path: &Path) -> String {
+    roots
+        .iter()
+        .find_map(|(dir, _)| path.strip_prefix(dir).ok())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// Populate the cache with an initial parse of every admitted file under `dir`.
+fn seed_cache(cache: &mut ParseCache, dir: &Path, filter: &SourceFilter) {
+    for entry in walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && filter.accepts(e.path()))
+    {
+        if let Err(e) = cache.update(entry.path()) {
+            eprintln!("Warning: Failed to parse {}: {}", entry.path().display(), e);
+        }
+    }
+}
+
+/// Regenerate TypeScript definitions from the cached per-file parse results.
+///
+/// `live_reload` threads the `--serve` port into the generator so the emitted
+/// client carries the WebSocket runtime.
+fn regenerate(config: &Config, cache: &ParseCache, live_reload: Option<u16>) -> Result<RegenStats> {
     let start = Instant::now();
-    
-    // Parse all Python files
-    let types = parser::parse_directory(&config.python.source_dir)?;
-    
-    // Generate and write TypeScript client
-    generator::write_definitions(
-        &config.typescript.output_file,
-        &types,
-        &config.api.base_url,
-    )?;
-    
+
+    let types = cache.merged()?;
+
+    // Generate and write TypeScript client for every configured target
+    for target in config.resolved_targets() {
+        let _diags = generator::write_definitions(
+            &target.output_file,
+            &types,
+            &target.base_url,
+            &target.transport,
+            target.generate_client,
+            live_reload,
+        )?;
+    }
+
     let duration = start.elapsed();
-    
+
     // Log stats
     let model_count = types.models.len();
     let enum_count = types.enums.len();
     let route_count = types.routes.len();
-    
+
     if model_count > 0 || enum_count > 0 || route_count > 0 {
-        println!(
-            "   {} models, {} enums, {} routes → {}",
-            model_count.to_string().bright_cyan(),
-            enum_count.to_string().bright_cyan(),
-            route_count.to_string().bright_cyan(),
-            config.typescript.output_file.display().to_string().green()
-        );
+        for target in config.resolved_targets() {
+            println!(
+                "   {} models, {} enums, {} routes → {}",
+                model_count.to_string().bright_cyan(),
+                enum_count.to_string().bright_cyan(),
+                route_count.to_string().bright_cyan(),
+                target.output_file.display().to_string().green()
+            );
+        }
+    }
+
+    Ok(RegenStats {
+        duration,
+        models: model_count,
+        routes: route_count,
+    })
+}
+
+/// Accept WebSocket connections on `127.0.0.1:port` and forward every message
+/// published on `tx` to each connected client until it disconnects.
+async fn serve_live_reload(port: u16, tx: broadcast::Sender<String>) -> Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let mut rx = tx.subscribe();
+        tokio::spawn(async move {
+            let ws = match tokio_tungstenite::accept_async(stream).await {
+                Ok(ws) => ws,
+                Err(_) => return,
+            };
+            let (mut write, mut read) = ws.split();
+            loop {
+                tokio::select! {
+                    // A regeneration was broadcast: push it to this client.
+                    msg = rx.recv() => match msg {
+                        Ok(text) => {
+                            if write.send(Message::Text(text)).await.is_err() {
+                                break;
+                            }
+                        }
+                        // Lagged clients skip missed frames rather than drop.
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    // Drain client frames so close/ping is observed promptly.
+                    incoming = read.next() => match incoming {
+                        Some(Ok(_)) => continue,
+                        _ => break,
+                    },
+                }
+            }
+        });
     }
-    
-    Ok(duration)
 }
 
 #[cfg(test)]
@@ -153,7 +375,7 @@ class User(BaseModel):
         
         let config = Config {
             python: crate::config::PythonConfig {
-                source_dir: python_dir,
+                source_dirs: vec![crate::config::SourceDir::Path(python_dir.clone())],
                 include: vec!["**/*.py".to_string()],
                 exclude: vec![],
             },
@@ -164,11 +386,22 @@ class User(BaseModel):
             api: crate::config::ApiConfig {
                 base_url: "http://localhost:8000".to_string(),
                 prefix: String::new(),
+                transport: crate::config::TransportConfig::default(),
             },
+            watch: crate::config::WatchConfig::default(),
+            targets: Vec::new(),
         };
         
-        let duration = regenerate(&config).unwrap();
-        assert!(duration.as_millis() < 500); // Should be reasonably fast
+        let filter = SourceFilter::new(
+            &python_dir,
+            &config.python.include,
+            &config.python.exclude,
+        )
+        .unwrap();
+        let mut cache = ParseCache::new();
+        seed_cache(&mut cache, &python_dir, &filter);
+        let stats = regenerate(&config, &cache, None).unwrap();
+        assert!(stats.duration.as_millis() < 500); // Should be reasonably fast
         
         let content = fs::read_to_string(&ts_file).unwrap();
         assert!(content.contains("interface User"));